@@ -0,0 +1,105 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+/// The answer to a part. Every integer type converts into `Number`, so answers compare equal
+/// regardless of the type a solution computed them in. Serializes as a plain number or string.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+// Written by hand: serde's untagged enums cannot deserialize into `i128`.
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl de::Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "an integer or a string")
+            }
+
+            fn visit_i64<E: de::Error>(self, n: i64) -> Result<Answer, E> {
+                Ok(Answer::from(n))
+            }
+
+            fn visit_u64<E: de::Error>(self, n: u64) -> Result<Answer, E> {
+                Ok(Answer::from(n))
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Answer, E> {
+                Ok(Answer::from(s))
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equality() {
+        assert_eq!(Answer::from(42_u16), Answer::from(42_i64));
+        assert_eq!(Answer::from(String::from("CMZ")), Answer::from("CMZ"));
+        assert_ne!(Answer::from(42), Answer::from("42"));
+    }
+
+    #[test]
+    fn test_serialization() {
+        let answers = vec![
+            Answer::from(u64::MAX),
+            Answer::from(-7),
+            Answer::from("#.\n.#"),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r##"[18446744073709551615,-7,"#.\n.#"]"##);
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+        assert_eq!(answers[0].to_string(), "18446744073709551615");
+    }
+}
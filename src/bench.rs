@@ -0,0 +1,152 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::Duration;
+
+use crate::{Answer, PartResult};
+
+/// Timing statistics for one part over repeated runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub runs: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchResult {
+    /// The benchmark as a single record, using the median as its elapsed time.
+    pub fn to_part_result(&self) -> PartResult {
+        PartResult {
+            day: self.day,
+            part: self.part,
+            answer: self.answer.clone(),
+            elapsed: self.median,
+        }
+    }
+}
+
+/// Calls `run` `warmup` times untimed, then `runs` times, collecting the elapsed times it reports.
+/// Stops at the first run that fails, warmup included.
+pub fn bench<E>(
+    run: impl Fn() -> Result<PartResult, E>,
+    warmup: u32,
+    runs: u32,
+) -> Result<BenchResult, E> {
+    for _ in 0..warmup {
+        run()?;
+    }
+
+    let first = run()?;
+    let mut samples: Vec<Duration> = Vec::with_capacity(runs.max(1) as usize);
+    samples.push(first.elapsed);
+    for _ in 1..runs {
+        samples.push(run()?.elapsed);
+    }
+
+    let (min, median, mean, stddev) = stats(&mut samples);
+
+    Ok(BenchResult {
+        day: first.day,
+        part: first.part,
+        answer: first.answer,
+        runs: samples.len() as u32,
+        min,
+        median,
+        mean,
+        stddev,
+    })
+}
+
+// Returns (min, median, mean, sample standard deviation) of a non-empty set of samples.
+fn stats(samples: &mut [Duration]) -> (Duration, Duration, Duration, Duration) {
+    samples.sort();
+
+    let n = samples.len();
+    let median = if n.is_multiple_of(2) {
+        (samples[n / 2 - 1] + samples[n / 2]) / 2
+    } else {
+        samples[n / 2]
+    };
+
+    let mean_ns: f64 = samples.iter().map(|s| s.as_nanos() as f64).sum::<f64>() / n as f64;
+    let variance: f64 = if n > 1 {
+        samples
+            .iter()
+            .map(|s| (s.as_nanos() as f64 - mean_ns).powi(2))
+            .sum::<f64>()
+            / (n - 1) as f64
+    } else {
+        0_f64
+    };
+
+    (
+        samples[0],
+        median,
+        Duration::from_nanos(mean_ns.round() as u64),
+        Duration::from_nanos(variance.sqrt().round() as u64),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut samples: Vec<Duration> = [40, 10, 30, 20]
+            .iter()
+            .map(|&ns| Duration::from_nanos(ns))
+            .collect();
+        let (min, median, mean, stddev) = stats(&mut samples);
+        assert_eq!(min, Duration::from_nanos(10));
+        assert_eq!(median, Duration::from_nanos(25));
+        assert_eq!(mean, Duration::from_nanos(25));
+        // sqrt(500 / 3)
+        assert_eq!(stddev, Duration::from_nanos(13));
+
+        let mut single = vec![Duration::from_nanos(7)];
+        assert_eq!(
+            stats(&mut single),
+            (
+                Duration::from_nanos(7),
+                Duration::from_nanos(7),
+                Duration::from_nanos(7),
+                Duration::ZERO
+            )
+        );
+    }
+
+    #[test]
+    fn test_bench() {
+        let result = bench(
+            || crate::solution::run_part(1, 1, || Ok("abc".len().into())),
+            2,
+            5,
+        )
+        .unwrap();
+        assert_eq!(result.runs, 5);
+        assert_eq!(result.answer, Some(Answer::from(3)));
+        assert!(result.min <= result.median);
+
+        // A run that fails is reported, not left out of the statistics
+        let runs = std::cell::Cell::new(0);
+        let failed = bench(
+            || {
+                runs.set(runs.get() + 1);
+                match runs.get() {
+                    3 => Err("third run failed"),
+                    _ => Ok(crate::solution::run_part(1, 1, || Ok(1.into())).unwrap()),
+                }
+            },
+            1,
+            5,
+        );
+        assert_eq!(failed, Err("third run failed"));
+    }
+}
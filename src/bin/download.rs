@@ -2,20 +2,27 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::{
+    cli,
+    client::{self, Client},
+};
 use std::process;
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
+    let year = cli::parse_year(&mut args)?;
+
+    let parsed = Args {
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
-    })
+        year,
+    };
+    cli::finish(args)?;
+    Ok(parsed)
 }
 
 fn main() {
@@ -27,20 +34,16 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
+    let client = match Client::from_env(args.year) {
+        Ok(client) => client,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
+            eprintln!("Failed to set up client: {}", e);
             process::exit(1);
         }
+    };
+
+    if let Err(e) = client::download(&client, args.day) {
+        eprintln!("Failed to download day {}: {}", args.day, e);
+        process::exit(1);
     }
 }
@@ -2,20 +2,42 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::{
+    cli,
+    client::{self, Client, ClientError},
+    markdown,
+};
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    process,
+};
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
+    refresh: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
+    let year = cli::parse_year(&mut args)?;
+    let refresh = args.contains("--refresh");
+
+    let parsed = Args {
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
-    })
+        year,
+        refresh,
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
+
+/// Fetches the puzzle description of a day and saves it to `src/puzzles`.
+fn fetch(day: u8, year: u16) -> Result<String, ClientError> {
+    let puzzle = Client::from_env(year)?.puzzle(day)?;
+    client::write_file(&client::puzzle_path(year, day), &puzzle)?;
+    Ok(puzzle)
 }
 
 fn main() {
@@ -27,20 +49,22 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+    let local = if args.refresh {
+        None
+    } else {
+        fs::read_to_string(client::puzzle_path(args.year, args.day)).ok()
+    };
 
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
+    let puzzle = match local {
+        Some(puzzle) => puzzle,
+        None => match fetch(args.day, args.year) {
+            Ok(puzzle) => puzzle,
+            Err(e) => {
+                eprintln!("Failed to fetch day {}: {}", args.day, e);
                 process::exit(1);
             }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            process::exit(1);
-        }
-    }
+        },
+    };
+
+    print!("{}", markdown::render(&puzzle, io::stdout().is_terminal()));
 }
@@ -2,24 +2,46 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::{cli, client, markdown};
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+const MODULE_TEMPLATE: &str = r###"%IMPORTS%%PARSER%
+pub fn parse(input: &str) -> Result<Vec<&str>, SolveError> {
+%PARSE_INPUT%}
+
+pub fn part_one(_lines: &[&str]) -> Result<Answer, SolveError> {
+    let answer: Option<%ANSWER_TYPE%> = None;
+    answer.map(Answer::from).ok_or(SolveError::Unsolved)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+pub fn part_two(_lines: &[&str]) -> Result<Answer, SolveError> {
+    let answer: Option<%ANSWER_TYPE%> = None;
+    answer.map(Answer::from).ok_or(SolveError::Unsolved)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day%DAY_PADDED%;
+
+impl Solution for Day%DAY_PADDED% {
+    const DAY: u8 = %DAY%;
+    type Params = ();
+    type Model<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(lines: &Vec<&str>, _: &()) -> Result<Answer, SolveError> {
+        part_one(lines)
+    }
+
+    fn part_two(lines: &Vec<&str>, _: &()) -> Result<Answer, SolveError> {
+        part_two(lines)
+    }
 }
 
 #[cfg(test)]
@@ -28,47 +50,357 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        // Named variants of the example go next to the default one
+        let examples = vec![("", %PART_ONE_ANSWER%)];
+        for (name, expected) in examples {
+            let input = crate::read_example(%YEAR%, %DAY%, name);
+            let answer = part_one(&parse(&input).unwrap());
+            assert_eq!(answer, expected, "example {:?}", name);
+        }
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        // Named variants of the example go next to the default one
+        let examples = vec![("", %PART_TWO_ANSWER%)];
+        for (name, expected) in examples {
+            let input = crate::read_example(%YEAR%, %DAY%, name);
+            let answer = part_two(&parse(&input).unwrap());
+            assert_eq!(answer, expected, "example {:?}", name);
+        }
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+const IMPORTS: &str = r###"use crate::{Answer, Solution, SolveError};
+"###;
+
+// Inserted with `--parser`: a line-by-line nom parser to adapt to the puzzle's input format.
+const PARSER_IMPORTS: &str = r###"use nom::character::complete::not_line_ending;
+
+use crate::{
+    parser::{lines, parse_all, ParseResult},
+    Answer, Solution, SolveError,
+};
+"###;
+
+const PARSER_TEMPLATE: &str = r###"
+// ----------------------------------------------------------------------------
+
+fn parse_line(input: &str) -> ParseResult<'_, &str> {
+    let (input, line) = not_line_ending(input)?;
+
+    Ok((input, line))
+}
+
+fn parse_input(input: &str) -> ParseResult<'_, Vec<&str>> {
+    let (input, line_vec) = lines(parse_line)(input)?;
+
+    Ok((input, line_vec))
+}
+
+// ----------------------------------------------------------------------------
+"###;
+
+const PARSE_INPUT: &str = r###"    // Parse input, reporting where it does not match
+    parse_all(input, parse_input)
+"###;
+
+// Parses the input into lines without `--parser`.
+const PARSE_LINES: &str = r###"    Ok(input.lines().collect())
+"###;
+
+const YEAR_TEMPLATE: &str = r###"/*
+ * Registry of the days solved for %YEAR%. `cargo scaffold` declares new days below and adds them
+ * to the list.
+ */
+register_days! {
+    %YEAR%;
+}
+"###;
+
+const REGISTRY_PATH: &str = "src/days/mod.rs";
+
+fn year_registry_path(year: u16) -> String {
+    format!("src/days/y{}/mod.rs", year)
+}
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// `--example N`: use the N-th code block of the puzzle (counted from 0) as the example.
+    example: Option<usize>,
+    /// `--type T`: the type the parts compute their answer as.
+    answer_type: &'static str,
+    /// `--parser`: start the module with a nom parser skeleton.
+    parser: bool,
+    /// `--force`: regenerate the module if it exists.
+    force: bool,
+}
+
+fn parse_answer_type(s: &str) -> Result<&'static str, String> {
+    match s {
+        "u32" => Ok("u32"),
+        "u64" => Ok("u64"),
+        "i64" => Ok("i64"),
+        "string" | "String" => Ok("String"),
+        _ => Err(format!(
+            "invalid answer type \"{}\", expected u32|u64|i64|string",
+            s
+        )),
+    }
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    let parsed = Args {
+        year: cli::parse_year(&mut args)?,
+        example: args.opt_value_from_str("--example")?,
+        answer_type: args
+            .opt_value_from_fn("--type", parse_answer_type)?
+            .unwrap_or("u32"),
+        parser: args.contains("--parser"),
+        force: args.contains("--force"),
+        day: args.free_from_str()?,
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
+
+// Creates the folder of `path`, e.g. when scaffolding the first day of a year.
+fn create_folder(path: &str) -> Result<(), std::io::Error> {
+    Path::new(path).parent().map_or(Ok(()), fs::create_dir_all)
 }
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    create_folder(path)?;
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
+// Modules are only replaced with `--force`.
+fn create_source_file(path: &str, force: bool) -> Result<File, std::io::Error> {
+    if force {
+        create_folder(path)?;
+        File::create(path)
+    } else {
+        safe_create_file(path)
+    }
+}
+
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    create_folder(path)?;
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+// Missing files and files with only whitespace count as empty.
+fn is_empty_file(path: &str) -> bool {
+    fs::read_to_string(path).map_or(true, |contents| contents.trim().is_empty())
+}
+
+// Rust expression for an expected answer, typed so that large numbers still compile.
+fn answer_literal(answer: Option<&str>) -> String {
+    match answer {
+        None => String::from("Err(SolveError::Unsolved)"),
+        Some(answer) if answer.parse::<i32>().is_ok() => format!("Ok(Answer::from({}))", answer),
+        Some(answer) if answer.parse::<i64>().is_ok() => {
+            format!("Ok(Answer::from({}_i64))", answer)
+        }
+        Some(answer) if answer.parse::<u64>().is_ok() => {
+            format!("Ok(Answer::from({}_u64))", answer)
+        }
+        Some(answer) => format!("Ok(Answer::from({:?}))", answer),
+    }
+}
+
+fn fill_template(
+    template: &str,
+    args: &Args,
+    answers: &(Option<String>, Option<String>),
+) -> String {
+    let day = args.day;
+    let (imports, parser, parse_input) = if args.parser {
+        (PARSER_IMPORTS, PARSER_TEMPLATE, PARSE_INPUT)
+    } else {
+        (IMPORTS, "", PARSE_LINES)
+    };
+
+    template
+        .replace("%IMPORTS%", imports)
+        .replace("%PARSER%", parser)
+        .replace("%PARSE_INPUT%", parse_input)
+        .replace("%ANSWER_TYPE%", args.answer_type)
+        .replace("%YEAR%", &args.year.to_string())
+        .replace("%DAY_PADDED%", &format!("{:02}", day))
+        .replace("%DAY%", &day.to_string())
+        .replace("%PART_ONE_ANSWER%", &answer_literal(answers.0.as_deref()))
+        .replace("%PART_TWO_ANSWER%", &answer_literal(answers.1.as_deref()))
+}
+
+// Adds `entry` to the end of the `name!` invocation in the file at `path`.
+// Returns whether it was added, i.e. `false` if it was registered already.
+fn register(path: &str, name: &str, entry: &str) -> Result<bool, std::io::Error> {
+    let registry = fs::read_to_string(path)?;
+    if registry.contains(entry) {
+        return Ok(false);
+    }
+
+    let end = registry
+        .find(&format!("{}! {{", name))
+        .and_then(|start| registry[start..].find("\n}").map(|end| start + end + 1))
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("no `{}!` invocation found", name),
+            )
+        })?;
+
+    let mut contents = registry[..end].to_string();
+    contents.push_str(entry);
+    contents.push_str(&registry[end..]);
+    fs::write(path, contents)?;
+    Ok(true)
+}
+
+// Declares `pub mod <module>;` in the file at `path`, after the modules declared there or else
+// before the `name!` invocation. Declared outside of the macro, the module is seen by rustfmt.
+fn declare_module(path: &str, name: &str, module: &str) -> Result<(), std::io::Error> {
+    let registry = fs::read_to_string(path)?;
+    let declaration = format!("pub mod {};\n", module);
+    if registry.contains(&declaration) {
+        return Ok(());
+    }
+
+    let (at, declaration) = match registry.rfind("\npub mod ") {
+        Some(start) => {
+            let end = registry[start + 1..]
+                .find('\n')
+                .map_or(registry.len(), |end| start + 1 + end + 1);
+            (end, declaration)
+        }
+        None => {
+            let start = registry.find(&format!("{}! {{", name)).ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("no `{}!` invocation found", name),
+                )
+            })?;
+            (start, format!("{}\n", declaration))
+        }
+    };
+
+    let mut contents = registry[..at].to_string();
+    contents.push_str(&declaration);
+    contents.push_str(&registry[at..]);
+    fs::write(path, contents)
+}
+
+// Creates the registry of the day's year on its first day, then adds the day to it.
+fn register_day(year: u16, day: u8) -> Result<bool, std::io::Error> {
+    let year_registry = year_registry_path(year);
+    if !Path::new(&year_registry).exists() {
+        fs::write(
+            &year_registry,
+            YEAR_TEMPLATE.replace("%YEAR%", &year.to_string()),
+        )?;
+        declare_module(REGISTRY_PATH, "register_years", &format!("y{}", year))?;
+        register(
+            REGISTRY_PATH,
+            "register_years",
+            &format!("    y{},\n", year),
+        )?;
+        println!("Registered year {} in \"{}\"", year, REGISTRY_PATH);
+    }
+
+    declare_module(&year_registry, "register_days", &format!("day{:02}", day))?;
+    register(
+        &year_registry,
+        "register_days",
+        &format!("    day{:02}::Day{:02},\n", day, day),
+    )
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
     };
 
-    let day_padded = format!("{:02}", day);
+    let (year, day) = (args.year, args.day);
+
+    let input_path = client::input_path(year, day);
+    let example_path = client::example_path(year, day);
+    let module_path = format!("src/days/y{}/day{:02}.rs", year, day);
+    let year_registry = year_registry_path(year);
+
+    // Example and expected answers are taken from the puzzle description if it was downloaded.
+    let puzzle = fs::read_to_string(client::puzzle_path(year, day)).unwrap_or_default();
+    let example = markdown::example(&puzzle, args.example);
+    let answers = markdown::example_answers(&puzzle);
+
+    // Existing modules are only replaced with `--force`, so nothing is written without it.
+    if !args.force && Path::new(&module_path).exists() {
+        eprintln!("Module file \"{}\" already exists.", &module_path);
+        eprintln!("Pass `--force` to regenerate an existing day.");
+        process::exit(1);
+    }
+
+    // Input and example files are never overwritten once they have contents, even with `--force`.
+    if !is_empty_file(&input_path) {
+        println!("Kept existing input file \"{}\"", &input_path);
+    } else {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", &input_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    if !is_empty_file(&example_path) {
+        println!("Kept existing example file \"{}\"", &example_path);
+    } else {
+        match example {
+            Some(example) => {
+                match create_folder(&example_path).and_then(|_| fs::write(&example_path, example)) {
+                    Ok(_) => {
+                        println!("Extracted example into \"{}\"", &example_path);
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to write example file: {}", e);
+                        process::exit(1);
+                    }
+                }
+            }
+            None => match create_file(&example_path) {
+                Ok(_) => {
+                    println!("Created empty example file \"{}\"", &example_path);
+                }
+                Err(e) => {
+                    eprintln!("Failed to create example file: {}", e);
+                    process::exit(1);
+                }
+            },
+        }
+    }
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    // The day is only created and registered once its files are in place.
+    let verb = if args.force { "Wrote" } else { "Created" };
 
-    let mut file = match safe_create_file(&module_path) {
+    let mut file = match create_source_file(&module_path, args.force) {
         Ok(file) => file,
         Err(e) => {
             eprintln!("Failed to create module file: {}", e);
@@ -76,9 +408,9 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(fill_template(MODULE_TEMPLATE, &args, &answers).as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("{} module file \"{}\"", verb, &module_path);
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -86,29 +418,22 @@ fn main() {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    match register_day(year, day) {
+        Ok(true) => {
+            println!("Registered day in \"{}\"", year_registry);
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
-        }
-    }
-
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+        Ok(false) => {
+            println!("Day is already registered in \"{}\"", year_registry);
         }
         Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+            eprintln!("Failed to register day: {}", e);
             process::exit(1);
         }
     }
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        day, year
     );
 }
@@ -0,0 +1,82 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    cli::{self, RunOptions},
+    days, output,
+};
+use std::process;
+
+struct Args {
+    options: RunOptions,
+    day: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let options = cli::parse(&mut args)?;
+    let year = cli::parse_year(&mut args)?;
+
+    let parsed = Args {
+        options,
+        day: args.free_from_str()?,
+        year,
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let day = match days::of_year(args.year)
+        .iter()
+        .find(|day| day.day == args.day)
+    {
+        Some(day) => day,
+        None => {
+            eprintln!(
+                "Day {} of {} is not registered. Create it with `cargo scaffold {} --year {}`.",
+                args.day, args.year, args.day, args.year
+            );
+            process::exit(1);
+        }
+    };
+
+    let input = match advent_of_code::try_read_file("inputs", day.year, day.day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input of day {}: {}", day.day, e);
+            output::emit_error(
+                day.day,
+                &format!("failed to read input: {}", e),
+                &args.options,
+            );
+            process::exit(1);
+        }
+    };
+
+    match day.run(&input, &[1, 2], &args.options) {
+        Ok(measurements) => {
+            for measurement in measurements {
+                measurement.emit(&args.options);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to solve day {}: {}", day.day, e);
+            if let Some(snippet) = e.error.snippet() {
+                eprintln!("{}", snippet);
+            }
+            output::emit_error(day.day, &e.to_string(), &args.options);
+            process::exit(1);
+        }
+    }
+}
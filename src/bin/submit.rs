@@ -0,0 +1,135 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    cli,
+    client::Client,
+    config::config,
+    days,
+    submissions::{Attempt, Submissions, Verdict},
+};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = cli::parse_year(&mut args)?;
+
+    let parsed = Args {
+        day: args.free_from_str()?,
+        part: args.free_from_fn(cli::parse_part)?,
+        answer: args.opt_free_from_str()?,
+        year,
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
+
+/// Runs the registered solution of `day` against its input.
+fn solve(year: u16, day: u8, part: u8) -> Result<String, String> {
+    let solution = days::of_year(year)
+        .iter()
+        .find(|d| d.day == day)
+        .ok_or_else(|| format!("day {} of {} is not registered", day, year))?;
+    let input = advent_of_code::try_read_file("inputs", year, day)
+        .map_err(|e| format!("could not read input of day {}: {}", day, e))?;
+
+    let result = solution
+        .solve(part, &input)
+        .map_err(|e| format!("could not solve day {}: {}", day, e))?;
+
+    match result.answer {
+        Some(answer) => Ok(answer.to_string()),
+        None => Err(format!("day {} part {} is not solved", day, part)),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => match solve(args.year, args.day, args.part) {
+            Ok(answer) => answer,
+            Err(e) => {
+                eprintln!("Failed to solve: {}", e);
+                process::exit(1);
+            }
+        },
+    };
+
+    if answer.trim().is_empty() || answer.contains('\n') {
+        eprintln!(
+            "Cannot submit \"{}\": answers have to be a single line.",
+            answer
+        );
+        process::exit(1);
+    }
+
+    let submissions_path = &config().submissions;
+    let mut submissions = match Submissions::load(submissions_path) {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", submissions_path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    if let Err(blocked) = submissions.check(args.year, args.day, args.part, &answer) {
+        eprintln!("Not submitting {}: {}.", answer, blocked);
+        process::exit(1);
+    }
+
+    let client = match Client::from_env(args.year) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to set up client: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "Submitting {} for day {} part {}, {}...",
+        answer, args.day, args.part, args.year
+    );
+    let verdict = match client.submit(args.day, args.part, &answer) {
+        Ok(verdict) => verdict,
+        Err(e) => {
+            eprintln!("Failed to submit: {}", e);
+            process::exit(1);
+        }
+    };
+
+    submissions.push(Attempt::new(
+        args.year,
+        args.day,
+        args.part,
+        &answer,
+        verdict.clone(),
+    ));
+    if let Err(e) = submissions.save(submissions_path) {
+        eprintln!("Failed to write \"{}\": {}", submissions_path.display(), e);
+        process::exit(1);
+    }
+
+    println!("---");
+    if verdict == Verdict::Correct {
+        println!("🎄 {} is correct!", answer);
+    } else {
+        println!("🎄 {} is {}.", answer, verdict);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,113 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    cli, days,
+    verify::{self, Answers, Verdict},
+    ANSI_BOLD, ANSI_RESET,
+};
+use std::process;
+
+struct Args {
+    record: bool,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        record: args.contains("--record"),
+        year: cli::parse_year(&mut args)?,
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let answers_path = verify::answers_path(args.year);
+    let mut answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", answers_path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    let mut mismatches: u32 = 0;
+    let mut recorded: u32 = 0;
+
+    for day in days::of_year(args.year) {
+        let input = match advent_of_code::try_read_file("inputs", day.year, day.day) {
+            Ok(input) => input,
+            Err(_) => {
+                println!("Day {:02}: missing input file", day.day);
+                continue;
+            }
+        };
+
+        for part in 1..=2 {
+            let result = match day.solve(part, &input) {
+                Ok(result) => result,
+                Err(e) => {
+                    mismatches += 1;
+                    println!(
+                        "Day {:02} Part {}: ❌ {}failed{} ({})",
+                        day.day, part, ANSI_BOLD, ANSI_RESET, e
+                    );
+                    continue;
+                }
+            };
+            let answer = result
+                .answer
+                .as_ref()
+                .map_or(String::from("not solved"), |answer| answer.to_string());
+
+            match verify::verify(&answers, &result) {
+                Verdict::Match => {
+                    println!("Day {:02} Part {}: ✅ match ({})", day.day, part, answer);
+                }
+                Verdict::Mismatch { expected } => {
+                    mismatches += 1;
+                    println!(
+                        "Day {:02} Part {}: ❌ {}mismatch{} (expected {}, got {})",
+                        day.day, part, ANSI_BOLD, ANSI_RESET, expected, answer
+                    );
+                }
+                Verdict::Missing if args.record && result.is_solved() => {
+                    recorded += 1;
+                    answers.set(day.day, part, &answer);
+                    println!("Day {:02} Part {}: 📝 recorded ({})", day.day, part, answer);
+                }
+                Verdict::Missing => {
+                    println!("Day {:02} Part {}: ❔ missing ({})", day.day, part, answer);
+                }
+            }
+        }
+    }
+
+    if recorded > 0 {
+        if let Err(e) = answers.save(&answers_path) {
+            eprintln!("Failed to write \"{}\": {}", answers_path.display(), e);
+            process::exit(1);
+        }
+    }
+
+    println!("---");
+    if mismatches > 0 {
+        println!(
+            "🎄 {} part(s) do not match their recorded answer.",
+            mismatches
+        );
+        process::exit(1);
+    }
+    println!("🎄 All recorded answers match.");
+}
@@ -0,0 +1,135 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{collections::BTreeSet, str::FromStr};
+
+use crate::{config::config, output::Format};
+
+/// Options shared by `cargo solve` and `cargo all`. The binaries parse them and pass them on, so
+/// the library never reads the command line itself.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// `--format text|json|csv`
+    pub format: Format,
+    /// `--bench N`: time every part over N runs instead of once.
+    pub bench: Option<u32>,
+    /// `--warmup N`: untimed runs before benchmarking. Defaults to 3.
+    pub warmup: u32,
+}
+
+/// Takes the shared options out of `args`, so binaries with free arguments do not mistake them
+/// for their own.
+pub fn parse(args: &mut pico_args::Arguments) -> Result<RunOptions, pico_args::Error> {
+    Ok(RunOptions {
+        format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+        bench: args.opt_value_from_str("--bench")?,
+        warmup: args.opt_value_from_str("--warmup")?.unwrap_or(3),
+    })
+}
+
+/// Fails if anything is left in `args` once a binary has taken its arguments, e.g. a misspelled
+/// flag.
+pub fn finish(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    let unused: Vec<String> = args
+        .finish()
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    if unused.is_empty() {
+        Ok(())
+    } else {
+        Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument(s) {}", unused.join(" ")),
+        })
+    }
+}
+
+/// Takes `-y/--year` out of `args`, falling back to the configured year.
+pub fn parse_year(args: &mut pico_args::Arguments) -> Result<u16, pico_args::Error> {
+    Ok(args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or(config().year))
+}
+
+/// Parses a part number, i.e. `1` or `2`.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part \"{}\", expected 1|2", s)),
+    }
+}
+
+/// A set of days given as a comma-separated list of days and ranges, e.g. `3,7,10-15`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DaySelection(BTreeSet<u8>);
+
+impl DaySelection {
+    /// Every day of the calendar.
+    pub fn all() -> Self {
+        DaySelection((1..=25).collect())
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+}
+
+impl Default for DaySelection {
+    fn default() -> Self {
+        DaySelection::all()
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.trim().parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day \"{}\", expected 1-25", s.trim())),
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = BTreeSet::new();
+
+        for item in s.split(',') {
+            match item.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (parse_day(start)?, parse_day(end)?);
+                    if start > end {
+                        return Err(format!("invalid range \"{}\"", item.trim()));
+                    }
+                    days.extend(start..=end);
+                }
+                None => {
+                    days.insert(parse_day(item)?);
+                }
+            }
+        }
+
+        Ok(DaySelection(days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_selection() {
+        let selection: DaySelection = "3,7,10-12, 25".parse().unwrap();
+        assert_eq!(selection, DaySelection([3, 7, 10, 11, 12, 25].into()));
+        assert!(selection.contains(11));
+        assert!(!selection.contains(13));
+        assert!(DaySelection::all().contains(25));
+
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("26".parse::<DaySelection>().is_err());
+        assert!("15-10".parse::<DaySelection>().is_err());
+        assert!("3,,7".parse::<DaySelection>().is_err());
+        assert!("a-b".parse::<DaySelection>().is_err());
+    }
+}
@@ -0,0 +1,582 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, fmt::Display, fs, io, path::Path};
+
+use crate::{config::config, markdown, submissions::Verdict};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/fspoettel/advent-of-code-rust)"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// Neither `AOC_SESSION` nor the configured session file holds a session cookie.
+    MissingSession,
+    /// The server answered `request` (e.g. "GET https://...") with a non-success status code.
+    Status {
+        request: String,
+        status: u16,
+        body: String,
+    },
+    /// The request did not complete, e.g. because the server could not be reached.
+    Transport {
+        request: String,
+        source: Box<ureq::Transport>,
+    },
+    /// The server answered with a page this client does not understand.
+    UnexpectedResponse { request: String, body: String },
+    /// Reading a response or writing a file failed. `context` says which.
+    Io { context: String, source: io::Error },
+}
+
+impl ClientError {
+    /// What the user can do about the error, if the response says so.
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            ClientError::Status { status: 400 | 500, body, .. } if body.contains("log in") => Some(
+                "Your session cookie is invalid or expired. Refresh AOC_SESSION or the session file.",
+            ),
+            ClientError::Status { status: 404, body, .. } if body.contains("before it unlocks") => {
+                Some("This puzzle is not unlocked yet.")
+            }
+            ClientError::Status { status: 404, .. } => {
+                Some("Check the day and year, the puzzle might not exist.")
+            }
+            _ => None,
+        }
+    }
+}
+
+// The first line of a response body, shortened to keep error messages readable.
+fn excerpt(body: &str) -> String {
+    let line = body.trim().lines().next().unwrap_or_default();
+    match line.char_indices().nth(200) {
+        Some((end, _)) => format!("{}...", &line[..end]),
+        None => line.to_string(),
+    }
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or write it to the `session_file` in aoc.toml (default: ~/.adventofcode.session)."
+            ),
+            ClientError::Status {
+                request,
+                status,
+                body,
+            } => {
+                write!(f, "{} failed with status {}", request, status)?;
+                if !body.trim().is_empty() {
+                    write!(f, ": {}", excerpt(body))?;
+                }
+                Ok(())
+            }
+            ClientError::Transport { request, source } => {
+                write!(f, "{} failed: {}", request, source.kind())?;
+                if let Some(message) = source.message() {
+                    write!(f, ": {}", message)?;
+                }
+                Ok(())
+            }
+            ClientError::UnexpectedResponse { request, body } => {
+                write!(f, "unexpected response to {}: {}", request, excerpt(body))
+            }
+            ClientError::Io { context, source } => write!(f, "{}: {}", context, source),
+        }?;
+
+        if let Some(hint) = self.hint() {
+            write!(f, "\n{}", hint)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Transport { source, .. } => Some(source.as_ref()),
+            ClientError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to the configured session file.
+pub fn session() -> Result<String, ClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    let path = config().session_file().ok_or(ClientError::MissingSession)?;
+
+    match fs::read_to_string(path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(ClientError::MissingSession),
+    }
+}
+
+/// The configured server to talk to, e.g. a local stand-in server.
+pub fn base_url() -> String {
+    config().base_url.clone()
+}
+
+/// Minimal client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            year,
+        }
+    }
+
+    /// A client for `year` using the session and base url from the environment.
+    pub fn from_env(year: u16) -> Result<Self, ClientError> {
+        Ok(Client::new(&base_url(), &session()?, year))
+    }
+
+    /// The puzzle input of a day.
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}/input", self.year, day))
+    }
+
+    /// The puzzle description of a day, converted to markdown.
+    pub fn puzzle(&self, day: u8) -> Result<String, ClientError> {
+        let html = self.get(&format!("/{}/day/{}", self.year, day))?;
+        Ok(puzzle_markdown(&html))
+    }
+
+    /// Submits `answer` for a part and returns how the server judged it.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict, ClientError> {
+        let path = format!("/{}/day/{}/answer", self.year, day);
+        let level = part.to_string();
+        let html = self.call(&path, Some(&[("level", &level), ("answer", answer)]))?;
+
+        parse_verdict(&html).ok_or_else(|| ClientError::UnexpectedResponse {
+            request: format!("POST {}{}", self.base_url, path),
+            body: html,
+        })
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        self.call(path, None)
+    }
+
+    /// Sends a GET request, or a POST request if there is a `form` to send.
+    fn call(&self, path: &str, form: Option<&[(&str, &str)]>) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let method = if form.is_some() { "POST" } else { "GET" };
+
+        if cfg!(debug_assertions) {
+            println!("{} {}", method, url);
+        }
+
+        let request = ureq::request(method, &url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT);
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        let request = format!("{} {}", method, url);
+        let response = response.map_err(|e| match e {
+            ureq::Error::Status(status, response) => ClientError::Status {
+                request: request.clone(),
+                status,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(transport) => ClientError::Transport {
+                request: request.clone(),
+                source: Box::new(transport),
+            },
+        })?;
+
+        response.into_string().map_err(|source| ClientError::Io {
+            context: format!("could not read response to {}", request),
+            source,
+        })
+    }
+}
+
+fn year_file(folder: &Path, year: u16, file_name: &str) -> String {
+    folder
+        .join(year.to_string())
+        .join(file_name)
+        .display()
+        .to_string()
+}
+
+pub fn input_path(year: u16, day: u8) -> String {
+    year_file(&config().inputs, year, &format!("{:02}.txt", day))
+}
+
+pub fn puzzle_path(year: u16, day: u8) -> String {
+    year_file(&config().puzzles, year, &format!("{:02}.md", day))
+}
+
+pub fn example_path(year: u16, day: u8) -> String {
+    year_file(&config().examples, year, &format!("{:02}.txt", day))
+}
+
+/// Writes `contents` to `path`, creating its folder if needed.
+pub fn write_file(path: &str, contents: &str) -> Result<(), ClientError> {
+    let path = Path::new(path);
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, contents))
+        .map_err(|source| ClientError::Io {
+            context: format!("could not write \"{}\"", path.display()),
+            source,
+        })
+}
+
+/// Fetches the input and puzzle description of a day and writes them to the client's year in
+/// `src/inputs` and `src/puzzles`.
+pub fn download(client: &Client, day: u8) -> Result<(), ClientError> {
+    let input_path = input_path(client.year, day);
+    let puzzle_path = puzzle_path(client.year, day);
+
+    println!("Downloading input for day {}, {}...", day, client.year);
+    let input = client.input(day)?;
+    println!("Fetching puzzle for day {}, {}...", day, client.year);
+    let puzzle = client.puzzle(day)?;
+
+    write_file(&input_path, &input)?;
+    write_file(&puzzle_path, &puzzle)?;
+
+    // Fill in the example, unless one was added by hand already.
+    let example_path = example_path(client.year, day);
+    let example = markdown::example(&puzzle, None);
+    let is_empty = fs::read_to_string(&example_path).map_or(true, |e| e.trim().is_empty());
+    if let Some(example) = example.filter(|_| is_empty) {
+        write_file(&example_path, &example)?;
+        println!("Extracted example into \"{}\"", &example_path);
+    }
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Reads the verdict from the page returned after submitting an answer.
+fn parse_verdict(html: &str) -> Option<Verdict> {
+    let start = html.find("<article")?;
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |i| start + i);
+    let text = html_to_markdown(&html[start..end]);
+
+    if text.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if text.contains("too high") {
+        Some(Verdict::TooHigh)
+    } else if text.contains("too low") {
+        Some(Verdict::TooLow)
+    } else if text.contains("That's not the right answer") {
+        Some(Verdict::Wrong)
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_string());
+        Some(Verdict::RateLimited { wait })
+    } else if text.contains("You don't seem to be solving the right level") {
+        Some(Verdict::WrongLevel)
+    } else {
+        None
+    }
+}
+
+/// Extracts the puzzle articles and the "Your puzzle answer was" lines from a day page.
+fn puzzle_markdown(html: &str) -> String {
+    let main = match (html.find("<main>"), html.rfind("</main>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut sections: Vec<&str> = vec![];
+    let mut rest = main;
+    while let Some(start) = [rest.find("<article"), rest.find("<p>Your puzzle answer")]
+        .into_iter()
+        .flatten()
+        .min()
+    {
+        let close = if rest[start..].starts_with("<article") {
+            "</article>"
+        } else {
+            "</p>"
+        };
+        let end = rest[start..]
+            .find(close)
+            .map_or(rest.len(), |i| start + i + close.len());
+        sections.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    sections
+        .iter()
+        .map(|section| html_to_markdown(section))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let replacement = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => name
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| name.strip_prefix('#').and_then(|n| n.parse().ok()))
+                .and_then(char::from_u32),
+        });
+
+        match (replacement, entity) {
+            (Some(c), Some((_, end))) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Converts the small subset of html used by puzzle pages to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut markdown, rest, in_pre);
+            break;
+        };
+        push_text(&mut markdown, &rest[..start], in_pre);
+
+        let end = rest[start..].find('>').map_or(rest.len(), |i| start + i);
+        let tag = &rest[start + 1..end];
+        rest = &rest[(end + 1).min(rest.len())..];
+
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let name = tag.split_whitespace().next().unwrap_or_default();
+
+        match (name, closing) {
+            ("h2", false) => markdown.push('\\'),
+            ("h2", true) => markdown.push_str("\n----------\n\n"),
+            ("p", true) => markdown.push_str("\n\n"),
+            ("ul", true) => markdown.push('\n'),
+            ("li", false) => markdown.push_str("* "),
+            ("li", true) => markdown.push('\n'),
+            ("pre", false) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                markdown.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => markdown.push('`'),
+            ("em", _) if !in_pre => markdown.push('*'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                markdown.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({})", href));
+            }
+            _ => {}
+        }
+    }
+
+    markdown
+}
+
+fn push_text(markdown: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+    if in_pre {
+        markdown.push_str(&text);
+    } else {
+        markdown.push_str(&text.replace('\n', " ").replace('*', "\\*"));
+    }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..end]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    #[test]
+    fn test_html_to_markdown() {
+        let html = "<article class=\"day-desc\"><h2>--- Day 1: Test ---</h2>\
+            <p>Find the <em>sum</em> of <code>a &lt; b</code>, see <a href=\"/2022/about\">here</a>: 2 * 3.</p>\
+            <pre><code>1000\n2000\n</code></pre>\
+            <ul><li>One <code><em>1</em></code></li></ul></article>\
+            <p>Your puzzle answer was <code>42</code>.</p>";
+
+        assert_eq!(
+            puzzle_markdown(&format!("<main>{}</main>", html)),
+            "\\--- Day 1: Test ---\n----------\n\n\
+             Find the *sum* of `a < b`, see [here](/2022/about): 2 \\* 3.\n\n\
+             ```\n1000\n2000\n```\n\n\
+             * One `*1*`\n\n\
+             \nYour puzzle answer was `42`.\n\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+
+        assert_eq!(
+            parse_verdict(&page(
+                "That's the right answer!  You are <span>one gold star</span> closer."
+            )),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            parse_verdict(&page("That's not the right answer.  If you're stuck, ...")),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            parse_verdict(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 39s left to wait.")),
+            Some(Verdict::RateLimited { wait: Some("39s".into()) })
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Some(Verdict::WrongLevel)
+        );
+        assert_eq!(parse_verdict("<main></main>"), None);
+    }
+
+    #[test]
+    fn test_client() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let mut requests: Vec<String> = vec![];
+            let responses = [
+                ("200 OK", "1\n2\n"),
+                (
+                    "404 Not Found",
+                    "Please don't repeatedly request this endpoint before it unlocks!",
+                ),
+            ];
+            for ((status, body), stream) in responses.into_iter().zip(listener.incoming()) {
+                let mut stream = stream.unwrap();
+                let mut request = String::new();
+                let mut reader = BufReader::new(&stream);
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+                requests.push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        let client = Client::new(&base_url, "secret", 2022);
+        assert_eq!(client.input(1).unwrap(), "1\n2\n");
+
+        let error = client.input(2).unwrap_err();
+        assert!(matches!(error, ClientError::Status { status: 404, .. }));
+        assert_eq!(error.hint(), Some("This puzzle is not unlocked yet."));
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "GET {}/2022/day/2/input failed with status 404: \
+                 Please don't repeatedly request this endpoint before it unlocks!\n\
+                 This puzzle is not unlocked yet.",
+                base_url
+            )
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input "));
+        assert!(requests[0].contains("session=secret"));
+        assert!(requests[1].starts_with("GET /2022/day/2/input "));
+
+        let error = client.input(3).unwrap_err();
+        assert!(matches!(error, ClientError::Transport { .. }));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+}
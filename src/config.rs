@@ -0,0 +1,212 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::OnceLock,
+};
+
+use serde::Deserialize;
+
+use crate::client::DEFAULT_BASE_URL;
+
+/// Read from the working directory unless `AOC_CONFIG` points elsewhere.
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+/// Year used without a config, and for records saved before years were tracked.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// [`DEFAULT_YEAR`] as a serde default, for records saved before years were tracked.
+pub fn default_year() -> u16 {
+    DEFAULT_YEAR
+}
+
+/// Project settings from `aoc.toml`. Every setting is optional and can be overridden by an
+/// environment variable, e.g. `AOC_INPUTS` for `inputs`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Year used by commands without `--year`. `AOC_YEAR`
+    pub year: u16,
+    /// Folder with a subfolder of puzzle inputs per year. `AOC_INPUTS`
+    pub inputs: PathBuf,
+    /// Folder with a subfolder of example inputs per year. `AOC_EXAMPLES`
+    pub examples: PathBuf,
+    /// Folder with a subfolder of puzzle descriptions per year. `AOC_PUZZLES`
+    pub puzzles: PathBuf,
+    /// Folder with a file of recorded answers per year. `AOC_ANSWERS`
+    pub answers: PathBuf,
+    /// File that `--save` appends timed runs to. `AOC_HISTORY`
+    pub history: PathBuf,
+    /// File that `cargo submit` records attempts in. `AOC_SUBMISSIONS`
+    pub submissions: PathBuf,
+    /// File whose benchmarking table `--readme` updates. `AOC_README`
+    pub readme: PathBuf,
+    /// File holding the session cookie if `AOC_SESSION` is not set. A leading `~` is the home
+    /// folder. `AOC_SESSION_FILE`
+    pub session_file: PathBuf,
+    /// Server to talk to, e.g. a local stand-in server. `AOC_BASE_URL`
+    pub base_url: String,
+    /// Refuse to save timings or update the readme from a debug build instead of warning.
+    /// `AOC_REQUIRE_RELEASE`
+    pub require_release: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: DEFAULT_YEAR,
+            inputs: PathBuf::from("src/inputs"),
+            examples: PathBuf::from("src/examples"),
+            puzzles: PathBuf::from("src/puzzles"),
+            answers: PathBuf::from("src/answers"),
+            history: PathBuf::from("benchmarks.json"),
+            submissions: PathBuf::from("submissions.json"),
+            readme: PathBuf::from("README.md"),
+            session_file: PathBuf::from("~/.adventofcode.session"),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            require_release: false,
+        }
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl Config {
+    /// Loads the config at `path`. A missing file uses the defaults.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|e| invalid_data(e.to_string())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Replaces settings with the environment variables that `var` returns a value for.
+    pub fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> io::Result<()> {
+        if let Some(year) = var("AOC_YEAR") {
+            self.year = year
+                .trim()
+                .parse()
+                .map_err(|_| invalid_data(format!("invalid AOC_YEAR \"{}\"", year)))?;
+        }
+        if let Some(require_release) = var("AOC_REQUIRE_RELEASE") {
+            self.require_release = match require_release.trim() {
+                "1" | "true" => true,
+                "0" | "false" => false,
+                _ => {
+                    return Err(invalid_data(format!(
+                        "invalid AOC_REQUIRE_RELEASE \"{}\", expected true|false",
+                        require_release
+                    )))
+                }
+            };
+        }
+
+        for (name, setting) in [
+            ("AOC_INPUTS", &mut self.inputs),
+            ("AOC_EXAMPLES", &mut self.examples),
+            ("AOC_PUZZLES", &mut self.puzzles),
+            ("AOC_ANSWERS", &mut self.answers),
+            ("AOC_HISTORY", &mut self.history),
+            ("AOC_SUBMISSIONS", &mut self.submissions),
+            ("AOC_README", &mut self.readme),
+            ("AOC_SESSION_FILE", &mut self.session_file),
+        ] {
+            if let Some(value) = var(name) {
+                *setting = PathBuf::from(value);
+            }
+        }
+        if let Some(base_url) = var("AOC_BASE_URL") {
+            self.base_url = base_url;
+        }
+
+        Ok(())
+    }
+
+    /// The folder of a kind of puzzle file: `inputs`, `examples`, `puzzles` or `answers`.
+    /// Anything else is a folder in `src`.
+    pub fn folder(&self, name: &str) -> PathBuf {
+        match name {
+            "inputs" => self.inputs.clone(),
+            "examples" => self.examples.clone(),
+            "puzzles" => self.puzzles.clone(),
+            "answers" => self.answers.clone(),
+            name => Path::new("src").join(name),
+        }
+    }
+
+    /// `session_file` with a leading `~` replaced by the home folder.
+    pub fn session_file(&self) -> Option<PathBuf> {
+        match self.session_file.strip_prefix("~") {
+            Ok(rest) => env::var_os("HOME")
+                .or_else(|| env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(rest)),
+            Err(_) => Some(self.session_file.clone()),
+        }
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Config of this process. Loaded on first use.
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(|| {
+        let path = env::var("AOC_CONFIG").unwrap_or_else(|_| CONFIG_PATH.to_string());
+        let config = Config::load(&path).and_then(|mut config| {
+            config.apply_env(|name| env::var(name).ok())?;
+            Ok(config)
+        });
+
+        match config {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Failed to load config \"{}\": {}", path, e);
+                process::exit(1);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config() {
+        let mut config: Config =
+            toml::from_str("year = 2023\ninputs = \"../inputs\"\nrequire_release = true\n")
+                .unwrap();
+        assert_eq!(config.year, 2023);
+        assert_eq!(config.folder("inputs"), PathBuf::from("../inputs"));
+        assert_eq!(config.folder("examples"), PathBuf::from("src/examples"));
+        assert_eq!(config.folder("answers"), PathBuf::from("src/answers"));
+        assert!(config.require_release);
+
+        config
+            .apply_env(|name| match name {
+                "AOC_YEAR" => Some("2021".into()),
+                "AOC_EXAMPLES" => Some("/tmp/examples".into()),
+                "AOC_REQUIRE_RELEASE" => Some("false".into()),
+                "AOC_HISTORY" => Some("/tmp/benchmarks.json".into()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(config.year, 2021);
+        assert_eq!(config.inputs, PathBuf::from("../inputs"));
+        assert_eq!(config.examples, PathBuf::from("/tmp/examples"));
+        assert!(!config.require_release);
+        assert_eq!(config.history, PathBuf::from("/tmp/benchmarks.json"));
+        assert_eq!(config.readme, PathBuf::from("README.md"));
+
+        assert!(config
+            .apply_env(|name| (name == "AOC_YEAR").then(|| "next".into()))
+            .is_err());
+        assert!(toml::from_str::<Config>("inputz = \"typo\"").is_err());
+    }
+}
@@ -0,0 +1,52 @@
+/*
+ * Registry of solved days, grouped by year. Each year lists its days in its own module, e.g.
+ * `./y2022/mod.rs`. `cargo scaffold` declares new years below and adds them to the list.
+ */
+use crate::solution::Day;
+
+// Builds the registry of a year from its day modules, which are declared next to it.
+macro_rules! register_days {
+    ($year:literal; $($module:ident::$solution:ident),* $(,)?) => {
+        pub const YEAR: u16 = $year;
+
+        /// All registered days of the year, in order.
+        pub const ALL: &[$crate::solution::Day] =
+            &[$($crate::solution::Day::new::<$module::$solution>(YEAR)),*];
+    };
+}
+
+// Builds the registry of all years from their modules, which are declared below.
+macro_rules! register_years {
+    ($($module:ident),* $(,)?) => {
+        /// All registered years with their days, in order.
+        pub const YEARS: &[(u16, &[Day])] = &[$(($module::YEAR, $module::ALL)),*];
+    };
+}
+
+/// The registered days of `year`, in order. Empty if the year has none.
+pub fn of_year(year: u16) -> &'static [Day] {
+    YEARS
+        .iter()
+        .find(|(registered, _)| *registered == year)
+        .map_or(&[], |(_, days)| days)
+}
+
+// Declared after the macros, which the year modules use.
+pub mod y2022;
+
+register_years! {
+    y2022,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_of_year() {
+        let days = of_year(2022);
+        assert_eq!(days.len(), 25);
+        assert!(days.iter().all(|day| day.year == 2022));
+        assert!(of_year(2015).is_empty());
+    }
+}
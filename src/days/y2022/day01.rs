@@ -0,0 +1,105 @@
+use crate::{Answer, Solution, SolveError};
+
+pub fn parse(input: &str) -> Result<Vec<u32>, SolveError> {
+    // Total calories carried by each elf
+    let mut elves: Vec<u32> = Vec::new();
+    // Accumulated calories for a single elf
+    let mut cum_calories: u32 = 0;
+
+    // Iterate over each line
+    for line in input.lines() {
+        // Accumulate calories for the elf
+        if !line.is_empty() {
+            let calories: u32 = line.parse().map_err(|_| {
+                SolveError::at(
+                    input,
+                    line,
+                    format!("expected 32-bit unsigned integer, found \"{}\"", line),
+                )
+            })?;
+            cum_calories += calories;
+        }
+        // Finished accumulating calories for elf
+        else {
+            elves.push(cum_calories);
+
+            // Reset accumulated calories for the next elf
+            cum_calories = 0;
+        }
+    }
+
+    // Fence post check since last elf isn't followed by empty line
+    elves.push(cum_calories);
+
+    Ok(elves)
+}
+
+pub fn part_one(elves: &[u32]) -> Result<Answer, SolveError> {
+    // The fattest elf
+    let max_calories: u32 = elves.iter().copied().max().unwrap_or_default();
+
+    Ok(Answer::from(max_calories))
+}
+
+pub fn part_two(elves: &[u32]) -> Result<Answer, SolveError> {
+    // Top 3 calories
+    let mut top_calories: [u32; 3] = [0; 3];
+    // Index of the minimum top calories
+    let mut index_min: usize = 0;
+
+    // Iterate each elf
+    for &elf_calories in elves {
+        // Replace the min top calories value if the elf's calories are greater
+        if top_calories[index_min] < elf_calories {
+            top_calories[index_min] = elf_calories;
+
+            // Re-compute the index of the min top calories
+            let mut min_calories: u32 = elf_calories;
+            for (i, calories) in top_calories.iter().enumerate() {
+                if calories < &min_calories {
+                    index_min = i;
+                    min_calories = *calories;
+                }
+            }
+        }
+    }
+
+    Ok(Answer::from(top_calories.iter().sum::<u32>()))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Params = ();
+    type Model<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Vec<u32>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(elves: &Vec<u32>, _: &()) -> Result<Answer, SolveError> {
+        part_one(elves)
+    }
+
+    fn part_two(elves: &Vec<u32>, _: &()) -> Result<Answer, SolveError> {
+        part_two(elves)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 1);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(24000)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 1);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(45000)));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{Answer, Solution, SolveError};
+
+// Each round as (opponent's move, second column), both as 0, 1 or 2
+type Round = (u8, u8);
+
+pub fn parse(input: &str) -> Result<Vec<Round>, SolveError> {
+    // Rounds of the strategy guide
+    let mut rounds: Vec<Round> = Vec::new();
+
+    // Iterate lines
+    for line in input.lines() {
+        // Line as bytes for indexing
+        let line_bytes: &[u8] = line.as_bytes();
+
+        match line_bytes {
+            [opp @ b'A'..=b'C', b' ', second @ b'X'..=b'Z'] => {
+                // Opps move
+                let opp_move: u8 = opp - b'A';
+                // Your move or round result
+                let second: u8 = second - b'X';
+
+                rounds.push((opp_move, second));
+            }
+            _ => {
+                return Err(SolveError::at(
+                    input,
+                    line,
+                    "expected opponent's move A-C, a space and X-Z",
+                ))
+            }
+        }
+    }
+
+    Ok(rounds)
+}
+
+pub fn part_one(rounds: &[Round]) -> Result<Answer, SolveError> {
+    // Score
+    let mut score: u32 = 0;
+
+    // Iterate rounds
+    for &(opp_move, my_move) in rounds {
+        // Add my move to score
+        score += my_move as u32 + 1;
+
+        // Round result
+        if my_move == opp_move {
+            score += 3; // Draw
+        } else if (opp_move + 1) % 3 == my_move {
+            // Use remainder to calculate r<p<s<r cycle
+            score += 6; // Win
+        }
+    }
+
+    Ok(Answer::from(score))
+}
+
+pub fn part_two(rounds: &[Round]) -> Result<Answer, SolveError> {
+    // Score
+    let mut score: u32 = 0;
+
+    // Iterate rounds
+    for &(opp_move, round_result) in rounds {
+        // Add round result to score
+        score += round_result as u32 * 3;
+
+        // Round result
+        match round_result {
+            0 => score += (opp_move as u32 + 2) % 3 + 1, // Lose
+            1 => score += opp_move as u32 + 1,           // Draw
+            _ => score += (opp_move as u32 + 1) % 3 + 1, // Win
+        }
+    }
+
+    Ok(Answer::from(score))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Params = ();
+    type Model<'a> = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Vec<Round>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(rounds: &Vec<Round>, _: &()) -> Result<Answer, SolveError> {
+        part_one(rounds)
+    }
+
+    fn part_two(rounds: &Vec<Round>, _: &()) -> Result<Answer, SolveError> {
+        part_two(rounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 2);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(15)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 2);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(12)));
+    }
+}
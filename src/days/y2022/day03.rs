@@ -1,3 +1,5 @@
+use crate::{Answer, Solution, SolveError};
+
 // Compartment in a rucksack
 struct Compartment {
     inventory: [bool; 52],
@@ -29,12 +31,38 @@ impl Compartment {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn parse(input: &str) -> Result<Vec<&str>, SolveError> {
+    // One line per rucksack
+    let rucksacks: Vec<&str> = input.lines().collect();
+
+    // Every item has to be a letter, and both compartments have to be equally large
+    if let Some(line) = rucksacks
+        .iter()
+        .find(|line| line.len() % 2 != 0 || !line.bytes().all(|c| c.is_ascii_alphabetic()))
+    {
+        return Err(SolveError::at(
+            input,
+            line,
+            "expected an even number of letters",
+        ));
+    }
+    // Rucksacks are grouped by three elves
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(SolveError::new(format!(
+            "expected groups of 3 rucksacks, found {} rucksacks",
+            rucksacks.len()
+        )));
+    }
+
+    Ok(rucksacks)
+}
+
+pub fn part_one(rucksacks: &[&str]) -> Result<Answer, SolveError> {
     // Priority sum
     let mut priority_sum: u32 = 0;
 
-    // Iterate lines
-    for line in input.lines() {
+    // Iterate rucksacks
+    for line in rucksacks {
         // Line as bytes for indexing
         let line_bytes: &[u8] = line.as_bytes();
         // Rucksack bound
@@ -61,7 +89,7 @@ pub fn part_one(input: &str) -> Option<u32> {
         }
     }
 
-    Some(priority_sum)
+    Ok(Answer::from(priority_sum))
 }
 
 // Loads items into compartment
@@ -77,19 +105,18 @@ fn load_compartment(line: &str) -> Compartment {
     compart
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(rucksacks: &[&str]) -> Result<Answer, SolveError> {
     // Priority sum
     let mut priority_sum: u32 = 0;
-    // Lines iterator
-    let mut lines = input.lines().peekable();
 
-    while lines.peek().is_some() {
+    // Iterate groups of three rucksacks
+    for group in rucksacks.chunks(3) {
         // Compartments
-        let compart_one: Compartment = load_compartment(lines.next().unwrap());
-        let compart_two: Compartment = load_compartment(lines.next().unwrap());
+        let compart_one: Compartment = load_compartment(group[0]);
+        let compart_two: Compartment = load_compartment(group[1]);
 
         // Iterate last compartment items
-        for c in lines.next().unwrap().bytes() {
+        for c in group[2].bytes() {
             if compart_one.exists(c) && compart_two.exists(c) {
                 priority_sum += (Compartment::get_index(c) as u32) + 1;
                 break;
@@ -97,13 +124,27 @@ pub fn part_two(input: &str) -> Option<u32> {
         }
     }
 
-    Some(priority_sum)
+    Ok(Answer::from(priority_sum))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Params = ();
+    type Model<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(rucksacks: &Vec<&str>, _: &()) -> Result<Answer, SolveError> {
+        part_one(rucksacks)
+    }
+
+    fn part_two(rucksacks: &Vec<&str>, _: &()) -> Result<Answer, SolveError> {
+        part_two(rucksacks)
+    }
 }
 
 #[cfg(test)]
@@ -112,13 +153,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_one(&input), Some(157));
+        let input = crate::read_file("examples", 2022, 3);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(157)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_two(&input), Some(70));
+        let input = crate::read_file("examples", 2022, 3);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(70)));
     }
 }
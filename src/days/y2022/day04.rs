@@ -0,0 +1,101 @@
+use crate::{Answer, Solution, SolveError};
+
+// Section range of an elf as (first, last)
+type Sections = (u8, u8);
+
+// Parse a section range such as "2-4"
+fn parse_sections(range: &str) -> Option<Sections> {
+    let (first, last) = range.split_once('-')?;
+
+    Some((first.parse::<u8>().ok()?, last.parse::<u8>().ok()?))
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Sections, Sections)>, SolveError> {
+    // Iterate lines
+    input
+        .lines()
+        .map(|line| {
+            // Split elfs
+            let (elf_one, elf_two) = line
+                .split_once(',')
+                .ok_or_else(|| SolveError::at(input, line, "expected a pair of elves"))?;
+
+            // Get elf one and elf two values
+            match (parse_sections(elf_one), parse_sections(elf_two)) {
+                (Some(elf_one), Some(elf_two)) => Ok((elf_one, elf_two)),
+                _ => Err(SolveError::at(
+                    input,
+                    line,
+                    "expected section ranges such as \"2-4,6-8\"",
+                )),
+            }
+        })
+        .collect()
+}
+
+pub fn part_one(pairs_list: &[(Sections, Sections)]) -> Result<Answer, SolveError> {
+    // Number of pairs
+    let mut pairs: u32 = 0;
+
+    // Iterate pairs
+    for (elf_one, elf_two) in pairs_list {
+        if (elf_one.0 <= elf_two.0 && elf_one.1 >= elf_two.1)
+            || (elf_one.0 >= elf_two.0 && elf_one.1 <= elf_two.1)
+        {
+            pairs += 1;
+        }
+    }
+
+    Ok(Answer::from(pairs))
+}
+
+pub fn part_two(pairs_list: &[(Sections, Sections)]) -> Result<Answer, SolveError> {
+    // Number of pairs
+    let mut pairs: u32 = 0;
+
+    // Iterate pairs
+    for (elf_one, elf_two) in pairs_list {
+        if (elf_one.1 >= elf_two.0) && (elf_two.1 >= elf_one.0) {
+            pairs += 1;
+        }
+    }
+
+    Ok(Answer::from(pairs))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Params = ();
+    type Model<'a> = Vec<(Sections, Sections)>;
+
+    fn parse(input: &str) -> Result<Vec<(Sections, Sections)>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(pairs_list: &Vec<(Sections, Sections)>, _: &()) -> Result<Answer, SolveError> {
+        part_one(pairs_list)
+    }
+
+    fn part_two(pairs_list: &Vec<(Sections, Sections)>, _: &()) -> Result<Answer, SolveError> {
+        part_two(pairs_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 4);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(2)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 4);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(4)));
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::LinkedList;
+
+use crate::{Answer, Solution, SolveError};
+
+// A crate move as (number of crates, source stack, destination stack)
+type Move = (u8, usize, usize);
+
+// Stacks of crates and the moves to rearrange them
+pub struct Procedure {
+    stacks: Vec<LinkedList<u8>>,
+    moves: Vec<Move>,
+}
+
+// Parse stack drawing, which is a slice of the input
+fn parse_stacks(input: &str, crates_input: &str) -> Result<Vec<LinkedList<u8>>, SolveError> {
+    // Split drawing into lines
+    let crate_lines: Vec<&str> = crates_input.lines().collect();
+    // Get number of stacks
+    let num_stacks: usize = crate_lines
+        .last()
+        .and_then(|numbers| numbers.split_whitespace().last())
+        .and_then(|number| number.parse::<usize>().ok())
+        .ok_or("expected stack numbers below the drawing")?;
+    // Hold crates
+    let mut stack_model: Vec<LinkedList<u8>> = vec![LinkedList::new(); num_stacks];
+
+    // Iterate stack level starting from bottom
+    for &level in crate_lines[0..crate_lines.len() - 1].iter().rev() {
+        // Offset first char '[' and iterate every 4th char
+        let crate_it = level.as_bytes()[1..level.len() - 1].iter().step_by(4);
+
+        // Iterate crates
+        for (i, c) in crate_it.enumerate() {
+            // Push char if
+            if *c != b' ' {
+                stack_model
+                    .get_mut(i)
+                    .ok_or_else(|| SolveError::at(input, level, "crate outside of the stacks"))?
+                    .push_back(*c);
+            }
+        }
+    }
+
+    Ok(stack_model)
+}
+
+// Parse crate moves, which are a slice of the input
+fn parse_moves(input: &str, moves_input: &str, num_stacks: usize) -> Result<Vec<Move>, SolveError> {
+    // Iterate crate moves
+    moves_input
+        .lines()
+        .map(|line| {
+            // Split into words
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+
+            match tokens[..] {
+                ["move", num_crates, "from", src_stack, "to", dst_stack] => {
+                    // Stacks are numbered from 1
+                    let stack = |number: &str| {
+                        number
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|n| (1..=num_stacks).contains(n))
+                            .map(|n| n - 1)
+                    };
+
+                    match (num_crates.parse::<u8>(), stack(src_stack), stack(dst_stack)) {
+                        (Ok(num_crates), Some(src_stack), Some(dst_stack)) => {
+                            Ok((num_crates, src_stack, dst_stack))
+                        }
+                        _ => Err(SolveError::at(input, line, "invalid crate count or stack")),
+                    }
+                }
+                _ => Err(SolveError::at(
+                    input,
+                    line,
+                    "expected \"move N from S to D\"",
+                )),
+            }
+        })
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Procedure, SolveError> {
+    // Split crates drawing and move instructions
+    let (crates_input, moves_input) = input
+        .split_once("\n\n")
+        .ok_or("expected a drawing and moves separated by an empty line")?;
+
+    // Generate stack model
+    let stacks: Vec<LinkedList<u8>> = parse_stacks(input, crates_input)?;
+    let moves: Vec<Move> = parse_moves(input, moves_input, stacks.len())?;
+
+    Ok(Procedure { stacks, moves })
+}
+
+// ----------------------------------------------------------------------------
+
+// Take the top crate off a stack
+fn pop_crate(stack_model: &mut [LinkedList<u8>], stack: usize) -> Result<u8, SolveError> {
+    stack_model[stack]
+        .pop_back()
+        .ok_or_else(|| SolveError::new(format!("no crate left on stack {}", stack + 1)))
+}
+
+// Move crates for part one
+fn move_one(moves: &[Move], stack_model: &mut [LinkedList<u8>]) -> Result<(), SolveError> {
+    // Iterate crate moves
+    for &(num_crates, src_stack, dst_stack) in moves {
+        // Move crates
+        for _ in 0..num_crates {
+            let c: u8 = pop_crate(stack_model, src_stack)?;
+            stack_model[dst_stack].push_back(c);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn part_one(procedure: &Procedure) -> Result<Answer, SolveError> {
+    // Copy of the stack model to rearrange
+    let mut stack_model: Vec<LinkedList<u8>> = procedure.stacks.clone();
+    // Move crates according to part one instructions
+    move_one(&procedure.moves, &mut stack_model)?;
+
+    // Get crates at top
+    let mut top_crates: String = String::new();
+    for stack in &stack_model {
+        top_crates.push(*stack.back().unwrap_or(&b' ') as char);
+    }
+
+    Ok(Answer::from(top_crates))
+}
+
+// ----------------------------------------------------------------------------
+
+// Move crates for part two
+fn move_two(moves: &[Move], stack_model: &mut [LinkedList<u8>]) -> Result<(), SolveError> {
+    // Iterate crate moves
+    for &(num_crates, src_stack, dst_stack) in moves {
+        // Move crates
+        let mut moved_crates: LinkedList<u8> = LinkedList::new();
+        for _ in 0..num_crates {
+            let c: u8 = pop_crate(stack_model, src_stack)?;
+            moved_crates.push_back(c);
+        }
+        for _ in 0..num_crates {
+            let c: u8 = moved_crates.pop_back().unwrap();
+            stack_model[dst_stack].push_back(c);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn part_two(procedure: &Procedure) -> Result<Answer, SolveError> {
+    // Copy of the stack model to rearrange
+    let mut stack_model: Vec<LinkedList<u8>> = procedure.stacks.clone();
+    // Move crates according to part two instructions
+    move_two(&procedure.moves, &mut stack_model)?;
+
+    // Get crates at top
+    let mut top_crates: String = String::new();
+    for stack in &stack_model {
+        top_crates.push(*stack.back().unwrap_or(&b' ') as char);
+    }
+
+    Ok(Answer::from(top_crates))
+}
+
+// ----------------------------------------------------------------------------
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Params = ();
+    type Model<'a> = Procedure;
+
+    fn parse(input: &str) -> Result<Procedure, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(procedure: &Procedure, _: &()) -> Result<Answer, SolveError> {
+        part_one(procedure)
+    }
+
+    fn part_two(procedure: &Procedure, _: &()) -> Result<Answer, SolveError> {
+        part_two(procedure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 5);
+        assert_eq!(
+            part_one(&parse(&input).unwrap()),
+            Ok(Answer::from("CMZ".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 5);
+        assert_eq!(
+            part_two(&parse(&input).unwrap()),
+            Ok(Answer::from("MCD".to_string()))
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Answer, Solution, SolveError};
+
+// Number of characters until start-of-packet marker for variable length sequence
+fn message_marker(input: &str, seq_len: usize) -> Option<u32> {
+    // Input as array of chars
+    let input_arr = input.as_bytes();
+
+    // Start pointer
+    let mut start: usize = 0;
+
+    // Iterate packets
+    for (end, char) in input_arr.iter().enumerate() {
+        // So that start pointer and end pointer do not count as duplicate
+        if start != end {
+            // Check that char at end pointer is unique from start pointer
+            #[allow(clippy::needless_range_loop)]
+            for i in start..end {
+                // If char is duplicate, move start pointer to the char after the duplicate
+                if char == &input_arr[i] {
+                    start = i + 1;
+                    break;
+                }
+            }
+
+            // When 4 unique chars in sequence is reached
+            if (end - start + 1) == seq_len {
+                return Some((end as u32) + 1);
+            }
+        }
+    }
+
+    // Solution doesn't exist
+    None
+}
+
+pub fn parse(input: &str) -> Result<&str, SolveError> {
+    // The datastream is a single line of lowercase letters
+    let datastream: &str = input.trim_end();
+    if !datastream.bytes().all(|c| c.is_ascii_lowercase()) {
+        return Err(SolveError::new(
+            "expected a single line of lowercase letters",
+        ));
+    }
+
+    Ok(datastream)
+}
+
+pub fn part_one(datastream: &str) -> Result<Answer, SolveError> {
+    message_marker(datastream, 4)
+        .map(Answer::from)
+        .ok_or(SolveError::new("no start-of-packet marker"))
+}
+
+pub fn part_two(datastream: &str) -> Result<Answer, SolveError> {
+    message_marker(datastream, 14)
+        .map(Answer::from)
+        .ok_or(SolveError::new("no start-of-message marker"))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Params = ();
+    type Model<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<&str, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(datastream: &&str, _: &()) -> Result<Answer, SolveError> {
+        part_one(datastream)
+    }
+
+    fn part_two(datastream: &&str, _: &()) -> Result<Answer, SolveError> {
+        part_two(datastream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 6);
+        assert_eq!(part_one(parse(&input).unwrap()), Ok(Answer::from(7)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 6);
+        assert_eq!(part_two(parse(&input).unwrap()), Ok(Answer::from(19)));
+    }
+}
@@ -1,5 +1,7 @@
 use std::collections::{BTreeMap, VecDeque};
 
+use crate::{Answer, Solution, SolveError};
+
 // Directory struct
 struct Directory {
     // name: String,                             // Name of dir
@@ -20,7 +22,7 @@ impl Directory {
 
 // ----------------------------------------------------------------------------
 
-fn generate_fs(input: &str) -> VecDeque<Directory> {
+fn generate_fs(input: &str) -> Result<VecDeque<Directory>, SolveError> {
     // Stores the filesystem structure with only sizes
     let mut filesystem: VecDeque<Directory> = VecDeque::new();
 
@@ -43,21 +45,25 @@ fn generate_fs(input: &str) -> VecDeque<Directory> {
 
         if command_type.starts_with("cd") {
             // Get dir name to change into
-            let dir_name: &str = command_type.rsplit_once(' ').unwrap().1;
+            let dir_name: &str = command_type
+                .rsplit_once(' ')
+                .ok_or_else(|| {
+                    SolveError::at(input, command_type, "expected a directory to cd into")
+                })?
+                .1;
 
             // Set current directory of the filesystem
-            match dir_name {
-                "/" => curr_position = 0,
-                ".." => curr_position = filesystem[curr_position].parent_dir.unwrap(),
-                _ => {
-                    curr_position = *filesystem[curr_position]
-                        .subdirs
-                        .clone()
-                        .unwrap()
-                        .get(dir_name)
-                        .unwrap()
-                }
-            }
+            let next_position: Option<usize> = match dir_name {
+                "/" => Some(0),
+                ".." => filesystem[curr_position].parent_dir,
+                _ => filesystem[curr_position]
+                    .subdirs
+                    .as_ref()
+                    .and_then(|subdirs| subdirs.get(dir_name))
+                    .copied(),
+            };
+            curr_position = next_position
+                .ok_or_else(|| SolveError::at(input, command_type, "no such directory"))?;
         } else if command_type.starts_with("ls") {
             // Ensures that "ls" output is not double counted
             if filesystem[curr_position].subdirs.is_none() {
@@ -73,7 +79,7 @@ fn generate_fs(input: &str) -> VecDeque<Directory> {
                         // Output is dir
                         if out.starts_with("dir") {
                             // Get dir name
-                            let dir_name: &str = out.split_whitespace().last().unwrap();
+                            let dir_name: &str = out.split_whitespace().last().unwrap_or_default();
                             // Index of the dir to insert
                             let insert_idx: usize = filesystem.len();
 
@@ -92,9 +98,14 @@ fn generate_fs(input: &str) -> VecDeque<Directory> {
                             let file_size: u64 = out
                                 .split_whitespace()
                                 .next()
-                                .unwrap()
-                                .parse::<u64>()
-                                .unwrap();
+                                .and_then(|size| size.parse::<u64>().ok())
+                                .ok_or_else(|| {
+                                    SolveError::at(
+                                        input,
+                                        out,
+                                        "expected \"dir NAME\" or \"SIZE NAME\"",
+                                    )
+                                })?;
                             dir_size += file_size;
                         }
                     }
@@ -116,63 +127,69 @@ fn generate_fs(input: &str) -> VecDeque<Directory> {
         }
     }
 
-    filesystem
+    Ok(filesystem)
 }
 
 // ----------------------------------------------------------------------------
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn parse(input: &str) -> Result<Vec<u64>, SolveError> {
     // Filesystem as Vector of Directories
-    let filesystem: VecDeque<Directory> = generate_fs(input);
+    let filesystem: VecDeque<Directory> = generate_fs(input)?;
 
+    // Only the sizes are needed, starting with the root dir
+    Ok(filesystem.iter().map(|d| d.size).collect())
+}
+
+pub fn part_one(dir_sizes: &[u64]) -> Result<Answer, SolveError> {
     // Calculate sum of sizes < 100000
     let mut total_size: u64 = 0;
-    for d in filesystem.iter() {
-        // println!("--- Directory ---");
-        // println!("Index: {i}");
-        // println!("Name: {}", d.name);
-        // println!("Parent Directory: {}", d.parent_dir.unwrap_or(0));
-        // println!("Size: {}", d.size);
-        // println!("------ Subdir ---");
-        // for (n, di) in d.subdirs.clone().unwrap() {
-        //     println!("    Name: {n}\tIndex: {di}");
-        // }
-
-        if d.size <= 100000 {
-            total_size += d.size;
+    for &size in dir_sizes {
+        if size <= 100000 {
+            total_size += size;
         }
     }
 
-    Some(total_size)
+    Ok(Answer::from(total_size))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    // Filesystem as Vector of Directories
-    let filesystem: VecDeque<Directory> = generate_fs(input);
-
+pub fn part_two(dir_sizes: &[u64]) -> Result<Answer, SolveError> {
     // Total capacity of disk
     let capacity: u64 = 70000000;
     // Needed space
     let req_space: u64 = 30000000;
     // Current used space
-    let used_space: u64 = capacity - filesystem[0].size;
+    let used_space: u64 = capacity - dir_sizes[0];
 
     // Track min dir size
     let mut min_size: u64 = u64::MAX;
-    for d in filesystem.iter() {
+    for &size in dir_sizes {
         // If deleting dir is gives enough space, and dir is a new minimum
-        if (used_space + d.size >= req_space) && (d.size < min_size) {
-            min_size = d.size;
+        if (used_space + size >= req_space) && (size < min_size) {
+            min_size = size;
         }
     }
 
-    Some(min_size)
+    Ok(Answer::from(min_size))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Params = ();
+    type Model<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(dir_sizes: &Vec<u64>, _: &()) -> Result<Answer, SolveError> {
+        part_one(dir_sizes)
+    }
+
+    fn part_two(dir_sizes: &Vec<u64>, _: &()) -> Result<Answer, SolveError> {
+        part_two(dir_sizes)
+    }
 }
 
 #[cfg(test)]
@@ -181,13 +198,16 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), Some(95437));
+        let input = crate::read_file("examples", 2022, 7);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(95437)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), Some(24933642));
+        let input = crate::read_file("examples", 2022, 7);
+        assert_eq!(
+            part_two(&parse(&input).unwrap()),
+            Ok(Answer::from(24933642))
+        );
     }
 }
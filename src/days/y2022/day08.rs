@@ -0,0 +1,97 @@
+use crate::{
+    helpers::{Grid, Pos, NEIGHBOURS_4},
+    Answer, Solution, SolveError,
+};
+
+// Generate grid map from input
+pub fn parse(input: &str) -> Result<Grid<u8>, SolveError> {
+    // Only tree heights are allowed
+    Grid::parse(input, |c| c.to_digit(10).map(|height| height as u8))
+}
+
+// ----------------------------------------------------------------------------
+
+// If tree is visible from any edge
+fn is_visible(map: &Grid<u8>, pos: Pos) -> bool {
+    // Look towards each edge; trees on the edge have nothing in the way
+    NEIGHBOURS_4
+        .iter()
+        .any(|&dir| map.ray(pos, dir).all(|other| map[other] < map[pos]))
+}
+
+pub fn part_one(map: &Grid<u8>) -> Result<Answer, SolveError> {
+    // Total visible trees
+    let vis_trees: usize = map.positions().filter(|&pos| is_visible(map, pos)).count();
+
+    Ok(Answer::from(vis_trees))
+}
+
+// ----------------------------------------------------------------------------
+
+// Calculates scenic score of tree
+fn scenic_score(map: &Grid<u8>, pos: Pos) -> u32 {
+    NEIGHBOURS_4
+        .iter()
+        .map(|&dir| {
+            // Count trees up to and including the first one at least as tall
+            let mut seen: u32 = 0;
+            for other in map.ray(pos, dir) {
+                seen += 1;
+                if map[pos] <= map[other] {
+                    break;
+                }
+            }
+            seen
+        })
+        .product()
+}
+
+pub fn part_two(map: &Grid<u8>) -> Result<Answer, SolveError> {
+    // Max scenic score; trees on the edge score 0
+    let max_score: u32 = map
+        .positions()
+        .map(|pos| scenic_score(map, pos))
+        .max()
+        .unwrap_or(0);
+
+    Ok(Answer::from(max_score))
+}
+
+// ----------------------------------------------------------------------------
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Params = ();
+    type Model<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Grid<u8>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(map: &Grid<u8>, _: &()) -> Result<Answer, SolveError> {
+        part_one(map)
+    }
+
+    fn part_two(map: &Grid<u8>, _: &()) -> Result<Answer, SolveError> {
+        part_two(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 8);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(21)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 8);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(8)));
+    }
+}
@@ -1,31 +1,54 @@
 use std::collections::HashSet;
 
 use nom::{
+    branch::alt,
     bytes::complete::tag,
-    character::complete::{self, alpha1, newline},
-    multi::separated_list1,
-    IResult,
+    character::complete::{self, char},
+    combinator::value,
+    error::context,
 };
 
+use crate::{
+    parser::{lines, parse_all, ParseResult},
+    Answer, Solution, SolveError,
+};
+
+// Move as (head offset per step, number of steps)
+type Move = ((i32, i32), u8);
+
 // ----------------------------------------------------------------------------
 
-fn parse_moves(input: &str) -> IResult<&str, (&str, u8)> {
-    let (input, direction) = alpha1(input)?;
+fn parse_moves(input: &str) -> ParseResult<'_, Move> {
+    // Direction as the offset of a single step
+    let (input, direction) = context(
+        "direction U, D, R or L",
+        alt((
+            value((0, 1), char('U')),
+            value((0, -1), char('D')),
+            value((1, 0), char('R')),
+            value((-1, 0), char('L')),
+        )),
+    )(input)?;
     let (input, _) = tag(" ")(input)?;
     let (input, steps) = complete::u8(input)?;
 
     Ok((input, (direction, steps)))
 }
 
-fn parse_instructions(instr: &str) -> IResult<&str, Vec<(&str, u8)>> {
-    let (instr, instr_vec) = separated_list1(newline, parse_moves)(instr)?;
+fn parse_instructions(instr: &str) -> ParseResult<'_, Vec<Move>> {
+    let (instr, instr_vec) = lines(parse_moves)(instr)?;
 
     Ok((instr, instr_vec))
 }
 
+pub fn parse(input: &str) -> Result<Vec<Move>, SolveError> {
+    // Parse list of move instrucitons
+    parse_all(input, parse_instructions)
+}
+
 // ----------------------------------------------------------------------------
 
-pub fn part_one(input: &str) -> Option<usize> {
+pub fn part_one(instructions: &[Move]) -> Result<Answer, SolveError> {
     // Track current coordinate of head and tail
     let mut head_coord: (i32, i32) = (0, 0);
     let mut tail_coord: (i32, i32) = (0, 0);
@@ -34,22 +57,14 @@ pub fn part_one(input: &str) -> Option<usize> {
     let mut visited_coord: HashSet<(i32, i32)> = HashSet::new();
     visited_coord.insert((0, 0));
 
-    // Parse list of move instrucitons
-    let (_, instructions): (&str, Vec<(&str, u8)>) = parse_instructions(input).unwrap();
-
-    for (dir, steps) in instructions {
+    for &(dir, steps) in instructions {
         // Perform moves
         for _ in 0..steps {
             let head_prev_loc: (i32, i32) = head_coord;
 
             // Move head a single step
-            match dir {
-                "U" => head_coord.1 += 1,
-                "D" => head_coord.1 -= 1,
-                "R" => head_coord.0 += 1,
-                "L" => head_coord.0 -= 1,
-                _ => panic!("Invalid direction: {dir}"),
-            }
+            head_coord.0 += dir.0;
+            head_coord.1 += dir.1;
 
             // Check if tail needs to move
             if tail_coord.0.abs_diff(head_coord.0) > 1 || tail_coord.1.abs_diff(head_coord.1) > 1 {
@@ -59,10 +74,10 @@ pub fn part_one(input: &str) -> Option<usize> {
         }
     }
 
-    Some(visited_coord.len())
+    Ok(Answer::from(visited_coord.len()))
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(instructions: &[Move]) -> Result<Answer, SolveError> {
     // Track coordinates of rope
     let mut rope: [(i32, i32); 10] = [(0, 0); 10];
 
@@ -70,20 +85,12 @@ pub fn part_two(input: &str) -> Option<usize> {
     let mut visited_coord: HashSet<(i32, i32)> = HashSet::new();
     visited_coord.insert((0, 0));
 
-    // Parse list of move instrucitons
-    let (_, instructions): (&str, Vec<(&str, u8)>) = parse_instructions(input).unwrap();
-
-    for (dir, steps) in instructions {
+    for &(dir, steps) in instructions {
         // Perform moves
         for _ in 0..steps {
             // Move head a single step
-            match dir {
-                "U" => rope[0].1 += 1,
-                "D" => rope[0].1 -= 1,
-                "R" => rope[0].0 += 1,
-                "L" => rope[0].0 -= 1,
-                _ => panic!("Invalid direction: {dir}"),
-            }
+            rope[0].0 += dir.0;
+            rope[0].1 += dir.1;
 
             // Move knots
             for i in 1..rope.len() {
@@ -110,13 +117,27 @@ pub fn part_two(input: &str) -> Option<usize> {
         }
     }
 
-    Some(visited_coord.len())
+    Ok(Answer::from(visited_coord.len()))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Params = ();
+    type Model<'a> = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Vec<Move>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(instructions: &Vec<Move>, _: &()) -> Result<Answer, SolveError> {
+        part_one(instructions)
+    }
+
+    fn part_two(instructions: &Vec<Move>, _: &()) -> Result<Answer, SolveError> {
+        part_two(instructions)
+    }
 }
 
 #[cfg(test)]
@@ -125,16 +146,20 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_one(&input), Some(13));
+        let input = crate::read_file("examples", 2022, 9);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(13)));
     }
 
     #[test]
     fn test_part_two() {
-        let input_one = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_two(&input_one), Some(1));
-
-        let input_two: &str = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20";
-        assert_eq!(part_two(input_two), Some(36));
+        for (name, expected) in [("", Ok(Answer::from(1))), ("large", Ok(Answer::from(36)))] {
+            let input = crate::read_example(2022, 9, name);
+            assert_eq!(
+                part_two(&parse(&input).unwrap()),
+                expected,
+                "example {:?}",
+                name
+            );
+        }
     }
 }
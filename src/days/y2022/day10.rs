@@ -1,36 +1,45 @@
 use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{self, newline},
-    multi::separated_list1,
-    sequence::preceded,
-    IResult, Parser,
+    branch::alt, bytes::complete::tag, character::complete, error::context, sequence::preceded,
+    Parser,
+};
+
+use crate::{
+    parser::{lines, parse_all, ParseResult},
+    Answer, Solution, SolveError,
 };
 
 // ----------------------------------------------------------------------------
 
-enum InstructionType {
+pub enum InstructionType {
     Noop,
     Addx(i32),
 }
 
 // Parse single instruction
-fn parse_instr(input: &str) -> IResult<&str, InstructionType> {
-    let (input, instr) = alt((
-        tag("noop").map(|_| InstructionType::Noop),
-        preceded(tag("addx "), complete::i32).map(InstructionType::Addx),
-    ))(input)?;
+fn parse_instr(input: &str) -> ParseResult<'_, InstructionType> {
+    let (input, instr) = context(
+        "noop or addx",
+        alt((
+            tag("noop").map(|_| InstructionType::Noop),
+            preceded(tag("addx "), complete::i32).map(InstructionType::Addx),
+        )),
+    )(input)?;
 
     Ok((input, instr))
 }
 
 // Parse list of instruction
-fn parse_instr_list(input: &str) -> IResult<&str, Vec<InstructionType>> {
-    let (input, instr_vec) = separated_list1(newline, parse_instr)(input)?;
+fn parse_instr_list(input: &str) -> ParseResult<'_, Vec<InstructionType>> {
+    let (input, instr_vec) = lines(parse_instr)(input)?;
 
     Ok((input, instr_vec))
 }
 
+pub fn parse(input: &str) -> Result<Vec<InstructionType>, SolveError> {
+    // List of instructions
+    parse_all(input, parse_instr_list)
+}
+
 // ----------------------------------------------------------------------------
 
 fn sig_p1(sum_signals: &mut i32, x: &i32, interval: &mut u32) {
@@ -40,7 +49,7 @@ fn sig_p1(sum_signals: &mut i32, x: &i32, interval: &mut u32) {
     *interval += 40;
 }
 
-pub fn part_one(input: &str) -> Option<i32> {
+pub fn part_one(instr_vec: &[InstructionType]) -> Result<Answer, SolveError> {
     // X register
     let mut x: i32 = 1;
     // Number of cycles
@@ -50,9 +59,6 @@ pub fn part_one(input: &str) -> Option<i32> {
     // Cycle interval
     let mut interval: u32 = 20;
 
-    // List of instructions
-    let (_, instr_vec): (&str, Vec<InstructionType>) = parse_instr_list(input).unwrap();
-
     for i in instr_vec {
         // Leave early after obtaining signals of interest
         if interval <= 220 {
@@ -85,7 +91,7 @@ pub fn part_one(input: &str) -> Option<i32> {
         }
     }
 
-    Some(sum_signals)
+    Ok(Answer::from(sum_signals))
 }
 
 // ----------------------------------------------------------------------------
@@ -101,26 +107,25 @@ fn pixel_cycle(x: i32, cycles: &mut usize, row_pixels: &mut [char; 40]) {
     *cycles += 1;
 }
 
-// Print pixels; reset row and cycle
-fn print_reset_cycle(cycles: &mut usize, row_pixels: &mut [char; 40]) {
+// Output row of pixels; reset row and cycle
+fn output_reset_cycle(cycles: &mut usize, row_pixels: &mut [char; 40], screen: &mut Vec<String>) {
     // Reset cycles
     *cycles = 0;
-    // Print pixels and reset
-    println!("{}", String::from_iter(*row_pixels));
+    // Output pixels and reset
+    screen.push(String::from_iter(*row_pixels));
     *row_pixels = ['.'; 40];
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two(instr_vec: &[InstructionType]) -> Result<Answer, SolveError> {
     // X register
     let mut x: i32 = 1;
     // Number of cycles
     let mut cycles: usize = 0;
 
-    // List of instructions
-    let (_, instr_vec): (&str, Vec<InstructionType>) = parse_instr_list(input).unwrap();
-
     // String builder
     let mut row_pixels: [char; 40] = ['.'; 40];
+    // Finished rows of the CRT
+    let mut screen: Vec<String> = Vec::new();
 
     for i in instr_vec {
         // Match instruction
@@ -129,9 +134,9 @@ pub fn part_two(input: &str) -> Option<String> {
                 // One pixel cycle
                 pixel_cycle(x, &mut cycles, &mut row_pixels);
 
-                // Print pixels and reset row
+                // Output pixels and reset row
                 if cycles == 40 {
-                    print_reset_cycle(&mut cycles, &mut row_pixels);
+                    output_reset_cycle(&mut cycles, &mut row_pixels, &mut screen);
                 }
             }
             InstructionType::Addx(n) => {
@@ -145,24 +150,38 @@ pub fn part_two(input: &str) -> Option<String> {
                         x += n;
                     }
 
-                    // Print pixels and reset row
+                    // Output pixels and reset row
                     if cycles == 40 {
-                        print_reset_cycle(&mut cycles, &mut row_pixels);
+                        output_reset_cycle(&mut cycles, &mut row_pixels, &mut screen);
                     }
                 }
             }
         }
     }
 
-    Some(String::from("End of Output"))
+    Ok(Answer::from(screen.join("\n")))
 }
 
 // ----------------------------------------------------------------------------
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Params = ();
+    type Model<'a> = Vec<InstructionType>;
+
+    fn parse(input: &str) -> Result<Vec<InstructionType>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(instr_vec: &Vec<InstructionType>, _: &()) -> Result<Answer, SolveError> {
+        part_one(instr_vec)
+    }
+
+    fn part_two(instr_vec: &Vec<InstructionType>, _: &()) -> Result<Answer, SolveError> {
+        part_two(instr_vec)
+    }
 }
 
 #[cfg(test)]
@@ -171,13 +190,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_one(&input), Some(13140));
+        let input = crate::read_file("examples", 2022, 10);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(13140)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), Some(String::from("End of Output")));
+        let input = crate::read_file("examples", 2022, 10);
+        let screen: &str = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(screen)));
     }
 }
@@ -0,0 +1,329 @@
+use std::collections::{BinaryHeap, VecDeque};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, newline, one_of, space1},
+    combinator::value,
+    error::context,
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded},
+};
+
+use crate::{
+    parser::{parse_all, separated, ParseResult},
+    Answer, Solution, SolveError,
+};
+
+//  Monkey operation
+#[derive(Clone)]
+struct Operation {
+    left: u64,
+    op: char,
+    right: u64,
+}
+
+// Monkey throw condition
+#[derive(Clone)]
+struct Test {
+    div_by: u64,
+    true_throw: usize,
+    false_throw: usize,
+}
+
+// stinky monke
+#[derive(Clone)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    test: Test,
+    inspect_count: u64,
+}
+
+impl Monkey {
+    // Perform basic arithmetic operation
+    fn perform_operation(left: u64, op: char, right: u64) -> u64 {
+        match op {
+            '+' => left + right,
+            '-' => left - right,
+            '*' => left * right,
+            '/' => left / right,
+            _ => unreachable!("operators are checked when parsing"),
+        }
+    }
+
+    // Inspect item
+    fn inspect(&mut self, relief: u64) -> u64 {
+        // Inspect item
+        let mut worry: u64 = self.items.pop_front().unwrap();
+
+        let left: u64 = if self.operation.left == 0 {
+            worry
+        } else {
+            self.operation.left
+        };
+        let right: u64 = if self.operation.right == 0 {
+            worry
+        } else {
+            self.operation.right
+        };
+        worry = Monkey::perform_operation(left, self.operation.op, right);
+
+        // Relief check
+        worry / relief
+    }
+
+    // Receive item
+    fn receive_item(&mut self, item: u64) {
+        self.items.push_back(item);
+    }
+}
+
+// Monkey inspect and throw
+fn inspect_throw_items(monkeys: &mut [Monkey], curr_idx: usize, relief: u64, reset: u64) {
+    // Update inspect count
+    monkeys[curr_idx].inspect_count += monkeys[curr_idx].items.len() as u64;
+
+    // Inspect and throw all items
+    while !monkeys[curr_idx].items.is_empty() {
+        // New worry level
+        let new: u64 = monkeys[curr_idx].inspect(relief) % reset;
+
+        // Test condition of where to throw item
+        let cond: &Test = &monkeys[curr_idx].test;
+        if new.is_multiple_of(cond.div_by) {
+            monkeys[cond.true_throw].receive_item(new);
+        } else {
+            monkeys[cond.false_throw].receive_item(new);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+// Parse starting items of monkey
+fn parse_start_items(input: &str) -> ParseResult<'_, VecDeque<u64>> {
+    let (input, items) = delimited(
+        context("starting items", tag("  Starting items: ")), // Discard matching text
+        separated_list1(tag(", "), complete::u64),            // Get space separated u64 as Vec
+        newline,                                              // Discard newline
+    )(input)?;
+
+    Ok((input, VecDeque::from_iter(items)))
+}
+
+// Parse side of operation, where old is stored as 0
+fn parse_operand(input: &str) -> ParseResult<'_, u64> {
+    context(
+        "old or a number",
+        alt((value(0, tag("old")), complete::u64)),
+    )(input)
+}
+
+// Parse operation
+fn parse_operation(input: &str) -> ParseResult<'_, Operation> {
+    // Parse left side of operation
+    let (input, left) = preceded(
+        context("operation", tag("  Operation: new = ")), // Discard matching text
+        parse_operand,                                    // Match old or number
+    )(input)?;
+
+    // Parse operand
+    let (input, op) = preceded(
+        space1,                                  // Discard space
+        context("+, -, * or /", one_of("+-*/")), // Match operator
+    )(input)?;
+
+    // Parse right side of operation
+    let (input, right) = delimited(
+        space1,        // Discard space
+        parse_operand, // Match old or number
+        newline,       // Discard newline
+    )(input)?;
+
+    Ok((input, Operation { left, op, right }))
+}
+
+// Parse test
+fn parse_test(input: &str) -> ParseResult<'_, Test> {
+    // Parse divide by line
+    let (input, div_by) = delimited(
+        context("test", tag("  Test: divisible by ")), // Discard text
+        complete::u64,                                 // Match u64
+        newline,                                       // Discard newline
+    )(input)?;
+
+    // Parse true
+    let (input, true_throw) = delimited(
+        context("if true", tag("    If true: throw to monkey ")), // Discard text
+        complete::u8,                                             // Match usize
+        newline,                                                  // Discard newline
+    )(input)?;
+
+    // Parse false
+    let (input, false_throw) = delimited(
+        context("if false", tag("    If false: throw to monkey ")), // Discard text
+        complete::u8,                                               // Match usize
+        newline,                                                    // Discard newline
+    )(input)?;
+
+    Ok((
+        input,
+        Test {
+            div_by,
+            true_throw: true_throw as usize,
+            false_throw: false_throw as usize,
+        },
+    ))
+}
+
+// Parse monkey
+fn parse_monkey(input: &str) -> ParseResult<'_, Monkey> {
+    // Discard first line
+    let (input, _) = delimited(
+        context("monkey", tag("Monkey ")), // Discard text
+        complete::u8,                      // Match monkey number
+        pair(tag(":"), newline),           // Discard colon and newline
+    )(input)?;
+
+    let (input, items) = parse_start_items(input)?;
+    let (input, operation) = parse_operation(input)?;
+    let (input, test) = parse_test(input)?;
+
+    Ok((
+        input,
+        Monkey {
+            items,
+            operation,
+            test,
+            inspect_count: 0,
+        },
+    ))
+}
+
+// Parse list of monke
+fn parse_monkey_list(input: &str) -> ParseResult<'_, Vec<Monkey>> {
+    let (input, monke_vec) = separated(newline, parse_monkey)(input)?;
+
+    Ok((input, monke_vec))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Monkey>, SolveError> {
+    let monkey_vec: Vec<Monkey> = parse_all(input, parse_monkey_list)?;
+
+    // Monkey business needs the two most active monkeys
+    if monkey_vec.len() < 2 {
+        return Err(SolveError::new("expected at least 2 monkeys"));
+    }
+    // Every monkey must test by a non-zero number and throw to a known monkey
+    for (i, monkey) in monkey_vec.iter().enumerate() {
+        let test: &Test = &monkey.test;
+        if test.div_by == 0 {
+            return Err(SolveError::new(format!(
+                "monkey {} tests divisibility by 0",
+                i
+            )));
+        }
+        if let Some(to) = [test.true_throw, test.false_throw]
+            .into_iter()
+            .find(|&to| to >= monkey_vec.len())
+        {
+            return Err(SolveError::new(format!(
+                "monkey {} throws to unknown monkey {}",
+                i, to
+            )));
+        }
+    }
+
+    Ok(monkey_vec)
+}
+
+// ----------------------------------------------------------------------------
+
+pub fn part_one(monkeys: &[Monkey]) -> Result<Answer, SolveError> {
+    // Throwing items around changes the monkeys, so work on a copy
+    let mut monkey_vec: Vec<Monkey> = monkeys.to_vec();
+
+    let mut reset: u64 = 1;
+    for d in monkey_vec.iter() {
+        reset *= d.test.div_by;
+    }
+
+    // 20 rounds
+    for _ in 0..20 {
+        // Each monke
+        for m in 0..monkey_vec.len() {
+            inspect_throw_items(&mut monkey_vec, m, 3, reset);
+        }
+    }
+
+    let mut monkey_business: BinaryHeap<u64> = monkey_vec.iter().map(|m| m.inspect_count).collect();
+    Ok(Answer::from(
+        monkey_business.pop().unwrap() * monkey_business.pop().unwrap(),
+    ))
+}
+
+pub fn part_two(monkeys: &[Monkey]) -> Result<Answer, SolveError> {
+    // Throwing items around changes the monkeys, so work on a copy
+    let mut monkey_vec: Vec<Monkey> = monkeys.to_vec();
+
+    let mut reset_mod: u64 = 1;
+    for d in monkey_vec.iter() {
+        reset_mod *= d.test.div_by;
+    }
+
+    // 10000 rounds
+    for _ in 0..10000 {
+        // Each monke
+        for m in 0..monkey_vec.len() {
+            inspect_throw_items(&mut monkey_vec, m, 1, reset_mod);
+        }
+    }
+
+    let mut monkey_business: BinaryHeap<u64> = monkey_vec.iter().map(|m| m.inspect_count).collect();
+    Ok(Answer::from(
+        monkey_business.pop().unwrap() * monkey_business.pop().unwrap(),
+    ))
+}
+
+// ----------------------------------------------------------------------------
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Params = ();
+    type Model<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(monkeys: &Vec<Monkey>, _: &()) -> Result<Answer, SolveError> {
+        part_one(monkeys)
+    }
+
+    fn part_two(monkeys: &Vec<Monkey>, _: &()) -> Result<Answer, SolveError> {
+        part_two(monkeys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 11);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(10605)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 11);
+        assert_eq!(
+            part_two(&parse(&input).unwrap()),
+            Ok(Answer::from(2713310158_u64))
+        );
+    }
+}
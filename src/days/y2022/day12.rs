@@ -0,0 +1,120 @@
+use crate::{
+    graph::{bfs_to, Path},
+    helpers::{Grid, Pos},
+    Answer, Solution, SolveError,
+};
+
+// If current node has an incoming edge to the other node
+fn is_neighbor(mut curr_node: u8, mut other_node: u8) -> bool {
+    if curr_node == b'S' {
+        curr_node = b'a';
+    } else if curr_node == b'E' {
+        curr_node = b'z';
+    }
+
+    if other_node == b'S' {
+        other_node = b'a';
+    } else if other_node == b'E' {
+        other_node = b'z';
+    }
+
+    curr_node <= other_node + 1
+}
+
+// Heightmap with the squares of interest
+pub struct Heightmap {
+    map: Grid<u8>,
+    // Square marked S
+    start: Pos,
+    // Square marked E
+    end: Pos,
+}
+
+// Parse map; find start and end square
+pub fn parse(input: &str) -> Result<Heightmap, SolveError> {
+    // Map must be a non-empty rectangle of elevations
+    let map: Grid<u8> = Grid::parse(input, |c| {
+        (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c as u8)
+    })?;
+
+    Ok(Heightmap {
+        start: map
+            .position(|&c| c == b'S')
+            .ok_or("heightmap has no start 'S'")?,
+        end: map
+            .position(|&c| c == b'E')
+            .ok_or("heightmap has no end 'E'")?,
+        map,
+    })
+}
+
+// Get shortest path from E back to the first square that `is_goal`
+fn shortest_distance(heightmap: &Heightmap, is_goal: impl FnMut(&Pos) -> bool) -> Option<usize> {
+    let map: &Grid<u8> = &heightmap.map;
+
+    // Walk backwards, to the squares the current one can be reached from
+    let path: Path<Pos, usize> = bfs_to(
+        [heightmap.end],
+        |&pos| {
+            map.neighbours4(pos)
+                .filter(move |&other| is_neighbor(map[pos], map[other]))
+        },
+        is_goal,
+    )?;
+
+    Some(path.cost)
+}
+
+// ----------------------------------------------------------------------------
+
+pub fn part_one(heightmap: &Heightmap) -> Result<Answer, SolveError> {
+    shortest_distance(heightmap, |&pos| pos == heightmap.start)
+        .map(Answer::from)
+        .ok_or(SolveError::new("no path from S to E"))
+}
+
+pub fn part_two(heightmap: &Heightmap) -> Result<Answer, SolveError> {
+    // Squares at the lowest elevation, including S
+    shortest_distance(heightmap, |&pos| matches!(heightmap.map[pos], b'a' | b'S'))
+        .map(Answer::from)
+        .ok_or(SolveError::new("no path from the lowest squares to E"))
+}
+
+// ----------------------------------------------------------------------------
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Params = ();
+    type Model<'a> = Heightmap;
+
+    fn parse(input: &str) -> Result<Heightmap, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(heightmap: &Heightmap, _: &()) -> Result<Answer, SolveError> {
+        part_one(heightmap)
+    }
+
+    fn part_two(heightmap: &Heightmap, _: &()) -> Result<Answer, SolveError> {
+        part_two(heightmap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 12);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(31)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 12);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(29)));
+    }
+}
@@ -2,17 +2,23 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{self, newline},
-    multi::{separated_list0, separated_list1},
+    combinator::opt,
+    error::context,
     sequence::{delimited, separated_pair},
-    IResult, Parser,
+    Parser,
 };
 
 use std::cmp;
 
+use crate::{
+    parser::{parse_all, separated, ParseResult},
+    Answer, Solution, SolveError,
+};
+
 // ----------------------------------------------------------------------------
 
 #[derive(PartialEq, Eq)]
-enum Packet {
+pub enum Packet {
     Integer(u8),
     List(Vec<Packet>),
 }
@@ -45,28 +51,32 @@ impl PartialOrd for Packet {
 // ----------------------------------------------------------------------------
 
 // Parse packet
-fn parse_packet(input: &str) -> IResult<&str, Packet> {
-    let (input, packet) = alt((
-        // Match Integer variant
-        complete::u8.map(Packet::Integer),
-        // Match inside contents of "[]"
-        delimited(
-            tag("["), // Discard '['
-            separated_list0(
-                tag(","),     // Discard ','
-                parse_packet, // Match Packet enum recursively
-            )
-            .map(Packet::List), // Match List variant
-            tag("]"), // Discard ']'
-        ),
-    ))(input)?;
+fn parse_packet(input: &str) -> ParseResult<'_, Packet> {
+    let (input, packet) = context(
+        "a number or a list",
+        alt((
+            // Match Integer variant
+            complete::u8.map(Packet::Integer),
+            // Match inside contents of "[]"
+            delimited(
+                tag("["), // Discard '['
+                // Lists may be empty
+                opt(separated(
+                    tag(","),     // Discard ','
+                    parse_packet, // Match Packet enum recursively
+                ))
+                .map(|packets| Packet::List(packets.unwrap_or_default())), // Match List variant
+                context("',' or ']'", tag("]")), // Discard ']'
+            ),
+        )),
+    )(input)?;
 
     Ok((input, packet))
 }
 
 // Parse pairs of packets
-fn parse_pairs_list(input: &str) -> IResult<&str, Vec<(Packet, Packet)>> {
-    let (input, pairs): (&str, Vec<(Packet, Packet)>) = separated_list1(
+fn parse_pairs_list(input: &str) -> ParseResult<'_, Vec<(Packet, Packet)>> {
+    let (input, pairs): (&str, Vec<(Packet, Packet)>) = separated(
         tag("\n\n"), // Discard 2 newlines
         separated_pair(
             parse_packet, // Match Packet enum
@@ -78,6 +88,11 @@ fn parse_pairs_list(input: &str) -> IResult<&str, Vec<(Packet, Packet)>> {
     Ok((input, pairs))
 }
 
+pub fn parse(input: &str) -> Result<Vec<(Packet, Packet)>, SolveError> {
+    // Get vector of pairs
+    parse_all(input, parse_pairs_list)
+}
+
 // ----------------------------------------------------------------------------
 
 // Same as comparator but in funciton form to get more understanding
@@ -130,10 +145,7 @@ fn order_type(left: &Packet, right: &Packet) -> String {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    // Get vector of pairs
-    let (_, pairs): (&str, Vec<(Packet, Packet)>) = parse_pairs_list(input).unwrap();
-
+pub fn part_one(pairs: &[(Packet, Packet)]) -> Result<Answer, SolveError> {
     let fn_sum: usize = pairs
         .iter()
         .enumerate()
@@ -159,11 +171,10 @@ pub fn part_one(input: &str) -> Option<usize> {
 
     assert_eq!(fn_sum, cmp_sum);
 
-    Some(cmp_sum)
+    Ok(Answer::from(cmp_sum))
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (_, pairs): (&str, Vec<(Packet, Packet)>) = parse_pairs_list(input).unwrap();
+pub fn part_two(pairs: &[(Packet, Packet)]) -> Result<Answer, SolveError> {
     let mut packet_vec: Vec<&Packet> = pairs.iter().flat_map(|(l, r)| vec![l, r]).collect();
 
     // Divider packets
@@ -177,13 +188,27 @@ pub fn part_two(input: &str) -> Option<usize> {
     let d_two_idx: usize = packet_vec.iter().position(|&p| p == &divider_two).unwrap() + 1;
     let d_six_idx: usize = packet_vec.iter().position(|&p| p == &divider_six).unwrap() + 1;
 
-    Some(d_two_idx * d_six_idx)
+    Ok(Answer::from(d_two_idx * d_six_idx))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Params = ();
+    type Model<'a> = Vec<(Packet, Packet)>;
+
+    fn parse(input: &str) -> Result<Vec<(Packet, Packet)>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(pairs: &Vec<(Packet, Packet)>, _: &()) -> Result<Answer, SolveError> {
+        part_one(pairs)
+    }
+
+    fn part_two(pairs: &Vec<(Packet, Packet)>, _: &()) -> Result<Answer, SolveError> {
+        part_two(pairs)
+    }
 }
 
 #[cfg(test)]
@@ -192,13 +217,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_one(&input), Some(13));
+        let input = crate::read_file("examples", 2022, 13);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(13)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_two(&input), Some(140));
+        let input = crate::read_file("examples", 2022, 13);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(140)));
     }
 }
@@ -1,36 +1,68 @@
-use std::collections::HashSet;
-
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, newline},
-    multi::separated_list1,
-    sequence::separated_pair,
-    IResult,
+use nom::{bytes::complete::tag, character::complete, error::context, sequence::separated_pair};
+
+use crate::{
+    helpers::{Grid, Pos},
+    parser::{lines, parse_all, separated, ParseResult},
+    Answer, Solution, SolveError,
 };
 
 // Parse rock paths from input of ranges
-fn parse_ranges(input: &str) -> IResult<&str, Vec<Vec<(u16, u8)>>> {
-    // Alternate between sep to discard, and f to extract
-    let (input, lines) = separated_list1(
-        newline, // Discard newline
+fn parse_ranges(input: &str) -> ParseResult<'_, Vec<Vec<(u16, u8)>>> {
+    // One path per line
+    let (input, paths) = lines(
         // Alternate between sep to discard, and f to extract
-        separated_list1(
+        separated(
             tag(" -> "), // Discard arrow
             // Extract pair separated by ,
             separated_pair(
-                complete::u16, // Match x
-                tag(","),      // Match and discard ,
-                complete::u8,  // Match y
+                complete::u16,            // Match x
+                context("','", tag(",")), // Match and discard ,
+                complete::u8,             // Match y
             ),
         ),
     )(input)?;
 
-    Ok((input, lines))
+    Ok((input, paths))
 }
 
 // Generate all points for the rocks
-fn generate_map(parsed_input: Vec<Vec<(u16, u8)>>) -> HashSet<(u16, u8)> {
-    HashSet::from_iter(parsed_input.iter().flat_map(|ranges| {
+pub fn parse(input: &str) -> Result<Grid<bool>, SolveError> {
+    // Rock paths as lists of points
+    let parsed_input: Vec<Vec<(u16, u8)>> = parse_all(input, parse_ranges)?;
+
+    // Sand falls into the abyss if there are no rocks to land on
+    if parsed_input.iter().all(|ranges| ranges.len() < 2) {
+        return Err(SolveError::new("expected at least one rock path"));
+    }
+
+    Ok(generate_map(parsed_input))
+}
+
+// Cave map where rocks are true, down to the level above the floor
+fn generate_map(parsed_input: Vec<Vec<(u16, u8)>>) -> Grid<bool> {
+    let rocks: Vec<(u16, u8)> = rock_points(&parsed_input);
+
+    // Deepest y level
+    let deepest_level: usize = rocks.iter().map(|&(_, y)| y as usize).max().unwrap_or(0);
+    // Wide enough for the rocks and for sand piling up to the spawn on the floor
+    let width: usize = rocks
+        .iter()
+        .map(|&(x, _)| x as usize)
+        .max()
+        .unwrap_or(0)
+        .max(SAND_SPAWN.0 + deepest_level + 2)
+        + 2;
+
+    let mut map: Grid<bool> = Grid::new(width, deepest_level + 2, false);
+    for (x, y) in rocks {
+        map[(x as usize, y as usize)] = true;
+    }
+    map
+}
+
+// Generate all points for the lines of rocks
+fn rock_points(parsed_input: &[Vec<(u16, u8)>]) -> Vec<(u16, u8)> {
+    Vec::from_iter(parsed_input.iter().flat_map(|ranges| {
         ranges.iter().enumerate().flat_map(|(i, (x_b, y_b))| {
             // Generate vector of points for the line of rocks
             let mut points: Vec<(u16, u8)> = Vec::new();
@@ -70,21 +102,13 @@ fn generate_map(parsed_input: Vec<Vec<(u16, u8)>>) -> HashSet<(u16, u8)> {
     }))
 }
 
-// bc i can't do it while generating the map at the same time
-fn get_deepest_level(map: &HashSet<(u16, u8)>) -> u8 {
-    map.iter()
-        .max_by(|(_, y_i), (_, y_j)| y_i.cmp(y_j))
-        .unwrap()
-        .1
+// The floor is 2 levels below the deepest rock, just below the map
+fn get_deepest_level(map: &Grid<bool>) -> usize {
+    map.height() - 2
 }
 
 // Drops sand from spawn location and returns point at rest
-fn drop_sand(
-    map: &mut HashSet<(u16, u8)>,
-    mut sand_coord: (u16, u8),
-    deepest_y: u8,
-    part_two: bool,
-) -> bool {
+fn drop_sand(map: &mut Grid<bool>, mut sand_coord: Pos, deepest_y: usize, part_two: bool) -> bool {
     // If sand is at the deepest level; part one
     if !part_two && sand_coord.1 >= deepest_y {
         return false;
@@ -94,7 +118,7 @@ fn drop_sand(
     // If executing part one or sand hits the floor
     if !part_two || sand_coord.1 + 1 < deepest_y + 2 {
         // Down
-        if !map.contains(&(sand_coord.0, sand_coord.1 + 1)) {
+        if !map[(sand_coord.0, sand_coord.1 + 1)] {
             // Update sand coord
             sand_coord.1 += 1;
 
@@ -102,7 +126,7 @@ fn drop_sand(
             return drop_sand(map, sand_coord, deepest_y, part_two);
         }
         // Down left
-        else if !map.contains(&(sand_coord.0 - 1, sand_coord.1 + 1)) {
+        else if !map[(sand_coord.0 - 1, sand_coord.1 + 1)] {
             // Update sand coord
             sand_coord.0 -= 1;
             sand_coord.1 += 1;
@@ -111,7 +135,7 @@ fn drop_sand(
             return drop_sand(map, sand_coord, deepest_y, part_two);
         }
         // Down right
-        else if !map.contains(&(sand_coord.0 + 1, sand_coord.1 + 1)) {
+        else if !map[(sand_coord.0 + 1, sand_coord.1 + 1)] {
             // Update sand coord
             sand_coord.0 += 1;
             sand_coord.1 += 1;
@@ -127,19 +151,18 @@ fn drop_sand(
     }
 
     // Add sand to map if can't fall anymore
-    map.insert(sand_coord)
+    !std::mem::replace(&mut map[sand_coord], true)
 }
 
 // Sand spawn location
-const SAND_SPAWN: (u16, u8) = (500, 0);
+const SAND_SPAWN: Pos = (500, 0);
 
-pub fn part_one(input: &str) -> Option<u16> {
-    // Generate the Set of points on the map
-    let (_, ranges): (&str, Vec<Vec<(u16, u8)>>) = parse_ranges(input).unwrap();
-    let mut map: HashSet<(u16, u8)> = generate_map(ranges);
+pub fn part_one(rocks: &Grid<bool>) -> Result<Answer, SolveError> {
+    // Sand piles up on a copy of the map
+    let mut map: Grid<bool> = rocks.clone();
 
     // Deepest y level
-    let deepest_level: u8 = get_deepest_level(&map);
+    let deepest_level: usize = get_deepest_level(&map);
     // Track number of sands placed
     let mut sand_count: u16 = 0;
 
@@ -148,16 +171,15 @@ pub fn part_one(input: &str) -> Option<u16> {
         sand_count += 1;
     }
 
-    Some(sand_count)
+    Ok(Answer::from(sand_count))
 }
 
-pub fn part_two(input: &str) -> Option<u16> {
-    // Generate the Set of points on the map
-    let (_, ranges): (&str, Vec<Vec<(u16, u8)>>) = parse_ranges(input).unwrap();
-    let mut map: HashSet<(u16, u8)> = generate_map(ranges);
+pub fn part_two(rocks: &Grid<bool>) -> Result<Answer, SolveError> {
+    // Sand piles up on a copy of the map
+    let mut map: Grid<bool> = rocks.clone();
 
     // Deepest y level
-    let deepest_level: u8 = get_deepest_level(&map);
+    let deepest_level: usize = get_deepest_level(&map);
     // Track number of sands placed
     let mut sand_count: u16 = 0;
 
@@ -166,13 +188,27 @@ pub fn part_two(input: &str) -> Option<u16> {
         sand_count += 1;
     }
 
-    Some(sand_count + 1)
+    Ok(Answer::from(sand_count + 1))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 14);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Params = ();
+    type Model<'a> = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Grid<bool>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(rocks: &Grid<bool>, _: &()) -> Result<Answer, SolveError> {
+        part_one(rocks)
+    }
+
+    fn part_two(rocks: &Grid<bool>, _: &()) -> Result<Answer, SolveError> {
+        part_two(rocks)
+    }
 }
 
 #[cfg(test)]
@@ -181,13 +217,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 14);
-        assert_eq!(part_one(&input), Some(24));
+        let input = crate::read_file("examples", 2022, 14);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(24)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 14);
-        assert_eq!(part_two(&input), Some(93));
+        let input = crate::read_file("examples", 2022, 14);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(93)));
     }
 }
@@ -1,25 +1,36 @@
 use std::collections::BTreeSet;
 
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, newline},
-    multi::separated_list1,
-    sequence::separated_pair,
-    IResult,
+use nom::{bytes::complete::tag, character::complete, error::context, sequence::separated_pair};
+
+use crate::{
+    parser::{lines, parse_all, ParseResult},
+    Answer, PuzzleParams, Solution, SolveError,
 };
 
 // So that clippy doesn't give me type_complexity warning
-struct Coordinate {
+pub struct Coordinate {
     x: i32,
     y: i32,
 }
 
+// Parse the numbers of "x=1, y=2" after the "x="
+fn parse_coordinate(input: &str) -> ParseResult<'_, (i32, i32)> {
+    separated_pair(
+        complete::i32,
+        context("\", y=\"", tag(", y=")),
+        complete::i32,
+    )(input)
+}
+
 // Parse a sensor and a beacon
-fn parse_sensor_beacon(input: &str) -> IResult<&str, (Coordinate, Coordinate)> {
-    let (input, _) = tag("Sensor at x=")(input)?;
-    let (input, s_coord) = separated_pair(complete::i32, tag(", y="), complete::i32)(input)?;
-    let (input, _) = tag(": closest beacon is at x=")(input)?;
-    let (input, b_coord) = separated_pair(complete::i32, tag(", y="), complete::i32)(input)?;
+fn parse_sensor_beacon(input: &str) -> ParseResult<'_, (Coordinate, Coordinate)> {
+    let (input, _) = context("\"Sensor at x=\"", tag("Sensor at x="))(input)?;
+    let (input, s_coord) = parse_coordinate(input)?;
+    let (input, _) = context(
+        "\": closest beacon is at x=\"",
+        tag(": closest beacon is at x="),
+    )(input)?;
+    let (input, b_coord) = parse_coordinate(input)?;
 
     Ok((
         input,
@@ -37,17 +48,39 @@ fn parse_sensor_beacon(input: &str) -> IResult<&str, (Coordinate, Coordinate)> {
 }
 
 // Parse sensors and their closest beacons
-fn parse_sb_list(input: &str) -> IResult<&str, Vec<(Coordinate, Coordinate)>> {
-    let (input, scans) = separated_list1(newline, parse_sensor_beacon)(input)?;
+fn parse_sb_list(input: &str) -> ParseResult<'_, Vec<(Coordinate, Coordinate)>> {
+    let (input, scans) = lines(parse_sensor_beacon)(input)?;
 
     Ok((input, scans))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn parse(input: &str) -> Result<Vec<(Coordinate, Coordinate)>, SolveError> {
     // Extract sensor and beacon locations
-    let (_, scans): (&str, Vec<(Coordinate, Coordinate)>) = parse_sb_list(input).unwrap();
+    parse_all(input, parse_sb_list)
+}
+
+// Scanned row and search bounds, which are smaller for the example
+pub struct Params {
+    // Row to check in part one
+    pub row_report: i32,
+    // Max coordinate in part two
+    pub max_coord: u32,
+}
+
+impl PuzzleParams for Params {
+    const REAL: Self = Params {
+        row_report: 2000000,
+        max_coord: 4000000,
+    };
+    const EXAMPLE: Self = Params {
+        row_report: 10,
+        max_coord: 20,
+    };
+}
+
+pub fn part_one(scans: &[(Coordinate, Coordinate)], params: &Params) -> Result<Answer, SolveError> {
     // Row to check
-    const ROW_REPORT: i32 = 2000000;
+    let row_report: i32 = params.row_report;
     // Hold ranges that are in the row
     let mut ranges: BTreeSet<(i32, i32)> = BTreeSet::new();
     // Break points in the ranges
@@ -60,17 +93,17 @@ pub fn part_one(input: &str) -> Option<u32> {
         let manh_dist: i32 = x_dist.abs() + y_dist.abs();
 
         // If the sensors coverage crosses our row of interest
-        if ((sensor.y - manh_dist)..=(sensor.y + manh_dist)).contains(&ROW_REPORT) {
+        if ((sensor.y - manh_dist)..=(sensor.y + manh_dist)).contains(&row_report) {
             // Calculate range of the sensors coverage along the row
-            let rad: i32 = manh_dist - (sensor.y).abs_diff(ROW_REPORT) as i32;
+            let rad: i32 = manh_dist - (sensor.y).abs_diff(row_report) as i32;
             let mut start: i32 = sensor.x - rad - 1;
             let mut end: i32 = sensor.x + rad;
 
             // If there is a sensor and/or beacon in the range
-            if sensor.y == ROW_REPORT {
+            if sensor.y == row_report {
                 break_points.insert(sensor.x);
             }
-            if beacon.y == ROW_REPORT {
+            if beacon.y == row_report {
                 break_points.insert(beacon.x);
             }
 
@@ -118,21 +151,19 @@ pub fn part_one(input: &str) -> Option<u32> {
         .sum::<u32>()
         - break_points.len() as u32;
 
-    Some(spots_taken)
+    Ok(Answer::from(spots_taken))
 }
 
 // from rust discord: checked the boundaries of the diamonds optimized search
-pub fn part_two(input: &str) -> Option<u64> {
-    // Extract sensor and beacon locations
-    let (_, scans): (&str, Vec<(Coordinate, Coordinate)>) = parse_sb_list(input).unwrap();
+pub fn part_two(scans: &[(Coordinate, Coordinate)], params: &Params) -> Result<Answer, SolveError> {
     // Max coordinate
-    const MAX_COORD: u32 = 4000000;
+    let max_coord: u32 = params.max_coord;
 
-    for i in 0..=MAX_COORD {
+    for i in 0..=max_coord {
         // Hold ranges that are in the row
         let mut ranges: BTreeSet<(u32, u32)> = BTreeSet::new();
 
-        for (sensor, beacon) in &scans {
+        for (sensor, beacon) in scans {
             // Calculate Manhattan distance
             let x_dist: i32 = sensor.x - beacon.x;
             let y_dist: i32 = sensor.y - beacon.y;
@@ -140,12 +171,12 @@ pub fn part_two(input: &str) -> Option<u64> {
 
             // If the sensors coverage crosses our row of interest
             let min_check: u32 = (sensor.y - manh_dist).max(0) as u32;
-            let max_check: u32 = ((sensor.y + manh_dist) as u32).min(MAX_COORD);
+            let max_check: u32 = ((sensor.y + manh_dist) as u32).min(max_coord);
             if (min_check..=max_check).contains(&i) {
                 // Calculate range of the sensors coverage along the row
                 let rad: i32 = manh_dist - (sensor.y).abs_diff(i as i32) as i32;
                 let mut start: u32 = (sensor.x - rad - 1).max(0) as u32;
-                let mut end: u32 = ((sensor.x + rad) as u32).min(MAX_COORD);
+                let mut end: u32 = ((sensor.x + rad) as u32).min(max_coord);
 
                 // Merge the range so none of the ranges overlap
                 ranges.retain(|&(r_start, r_end)| {
@@ -174,43 +205,70 @@ pub fn part_two(input: &str) -> Option<u64> {
             }
         }
 
-        // If there's an open spot in the ranges
-        let r: (u32, u32) = *ranges.iter().next().unwrap();
+        // If there's an open spot in the ranges; with no ranges, the whole row is open
+        let Some(&r) = ranges.iter().next() else {
+            return Ok(Answer::from(i as u64));
+        };
         // Open spot is somewhere in middle
         if ranges.len() > 1 {
             let x: u32 = r.1 + 1;
-            return Some((x as u64) * 4000000 + i as u64);
+            return Ok(Answer::from((x as u64) * 4000000 + i as u64));
         }
         // Open spot is along the boundary
-        else if r.0 != 0 || r.1 != MAX_COORD {
-            let x: u32 = if r.0 != 0 { 0 } else { MAX_COORD };
-            return Some((x as u64) * 4000000 + i as u64);
+        else if r.0 != 0 || r.1 != max_coord {
+            let x: u32 = if r.0 != 0 { 0 } else { max_coord };
+            return Ok(Answer::from((x as u64) * 4000000 + i as u64));
         }
     }
 
-    None
+    Err(SolveError::new("no position for the distress beacon"))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 15);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Params = Params;
+    type Model<'a> = Vec<(Coordinate, Coordinate)>;
+
+    fn parse(input: &str) -> Result<Vec<(Coordinate, Coordinate)>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(
+        scans: &Vec<(Coordinate, Coordinate)>,
+        params: &Params,
+    ) -> Result<Answer, SolveError> {
+        part_one(scans, params)
+    }
+
+    fn part_two(
+        scans: &Vec<(Coordinate, Coordinate)>,
+        params: &Params,
+    ) -> Result<Answer, SolveError> {
+        part_two(scans, params)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    #[allow(unused_imports)]
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let _input = advent_of_code::read_file("examples", 15);
-        // assert_eq!(part_one(&input), Some(26));
+        let input = crate::read_file("examples", 2022, 15);
+        assert_eq!(
+            part_one(&parse(&input).unwrap(), &Params::EXAMPLE),
+            Ok(Answer::from(26))
+        );
     }
 
     #[test]
     fn test_part_two() {
-        let _input = advent_of_code::read_file("examples", 15);
-        // assert_eq!(part_two(&input), Some(56000011));
+        let input = crate::read_file("examples", 2022, 15);
+        assert_eq!(
+            part_two(&parse(&input).unwrap(), &Params::EXAMPLE),
+            Ok(Answer::from(56000011))
+        );
     }
 }
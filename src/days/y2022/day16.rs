@@ -3,10 +3,17 @@ use std::collections::HashMap;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{self, alpha1, newline},
+    character::complete::{self, alpha1},
+    error::context,
     multi::separated_list1,
-    sequence::delimited,
-    IResult, Parser,
+    sequence::{delimited, terminated},
+    Parser,
+};
+
+use crate::{
+    graph::all_pairs,
+    parser::{lines, parse_all, ParseResult},
+    Answer, Solution, SolveError,
 };
 
 #[derive(Eq, PartialEq, Debug)]
@@ -16,6 +23,13 @@ struct Valve {
     tunnels_to: Vec<String>,
 }
 
+// Relevant valves with the shortest paths between them
+pub struct Network {
+    valves: Vec<Valve>,
+    condensed_paths: Vec<Vec<u8>>,
+    start_valve: usize,
+}
+
 #[derive(Eq, PartialEq, Hash)]
 struct ValveState {
     current_valve: usize,
@@ -23,15 +37,21 @@ struct ValveState {
     time_left: u8,
 }
 
-fn parse_valve(input: &str) -> IResult<&str, Valve> {
-    let (input, name) = delimited(tag("Valve "), alpha1, tag(" has flow "))(input)?;
-    let (input, rate) = delimited(
-        tag("rate="),
+fn parse_valve(input: &str) -> ParseResult<'_, Valve> {
+    let (input, name) = delimited(
+        context("\"Valve \"", tag("Valve ")),
+        alpha1,
+        context("\" has flow rate=\"", tag(" has flow rate=")),
+    )(input)?;
+    let (input, rate) = terminated(
         complete::u32,
-        alt((
-            tag("; tunnels lead to valves "),
-            tag("; tunnel leads to valve "),
-        )),
+        context(
+            "\"; tunnels lead to valves \"",
+            alt((
+                tag("; tunnels lead to valves "),
+                tag("; tunnel leads to valve "),
+            )),
+        ),
     )(input)?;
     let (input, tunnels) = separated_list1(tag(", "), alpha1.map(String::from))(input)?;
 
@@ -45,34 +65,24 @@ fn parse_valve(input: &str) -> IResult<&str, Valve> {
     ))
 }
 
-fn parse_network(input: &str) -> IResult<&str, Vec<Valve>> {
-    let (input, valves) = separated_list1(newline, parse_valve)(input)?;
+fn parse_network(input: &str) -> ParseResult<'_, Vec<Valve>> {
+    let (input, valves) = lines(parse_valve)(input)?;
 
     Ok((input, valves))
 }
 
-// Calculate min distance from every pair of valves
-fn floyd_warshall(valves: &[Valve], valve_indicies: &HashMap<&str, usize>) -> Vec<Vec<u8>> {
-    let mut m: Vec<Vec<u8>> = vec![vec![u8::MAX; valves.len()]; valve_indicies.len()];
-    for (i, valve) in valves.iter().enumerate() {
-        m[i][i] = 0;
-        for to_valve in &valve.tunnels_to {
-            let to_idx: usize = *valve_indicies.get(to_valve as &str).unwrap();
-            m[i][to_idx] = 1;
-        }
-    }
-
-    for k in 0..valves.len() {
-        for i in 0..valves.len() {
-            for j in 0..valves.len() {
-                if m[i][k] != u8::MAX && m[k][j] != u8::MAX && m[i][k] + m[k][j] < m[i][j] {
-                    m[i][j] = m[i][k] + m[k][j];
-                }
-            }
-        }
-    }
-
-    m
+// Calculate min distance from every pair of valves; u8::MAX if one can't reach the other
+fn shortest_paths(valves: &[Valve], valve_indicies: &HashMap<&str, usize>) -> Vec<Vec<u8>> {
+    // Every tunnel takes 1 minute
+    all_pairs(valves.len(), |i| {
+        valves[i]
+            .tunnels_to
+            .iter()
+            .map(|to_valve| (valve_indicies[to_valve as &str], 1))
+    })
+    .into_iter()
+    .map(|row| row.into_iter().map(|m| m.unwrap_or(u8::MAX)).collect())
+    .collect()
 }
 
 // traveling salesman
@@ -96,7 +106,7 @@ fn traveling_salesman(
         }
 
         // Check if there is enough time to go to valve and open
-        let time_taken: u8 = paths[current_valve][to_valve] + 1;
+        let time_taken: u8 = paths[current_valve][to_valve].saturating_add(1);
         if time_left < time_taken {
             continue;
         }
@@ -153,10 +163,9 @@ fn traveling_salesman(
     max_pressure
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn parse(input: &str) -> Result<Network, SolveError> {
     // Parse valves
-    let (_, mut valves): (&str, Vec<Valve>) = parse_network(input).unwrap();
-    const TIME_LIMIT: u8 = 30;
+    let mut valves: Vec<Valve> = parse_all(input, parse_network)?;
 
     // Get indicies of valves and relevant valves
     let mut valve_indicies: HashMap<&str, usize> = HashMap::with_capacity(valves.len());
@@ -167,8 +176,21 @@ pub fn part_one(input: &str) -> Option<u32> {
             irrelevant_valves.push(i);
         }
     }
+    // Every tunnel must lead to a known valve
+    for valve in valves.iter() {
+        if let Some(to_valve) = valve
+            .tunnels_to
+            .iter()
+            .find(|&to_valve| !valve_indicies.contains_key(to_valve as &str))
+        {
+            return Err(SolveError::new(format!(
+                "tunnel from {} to unknown valve {}",
+                valve.name, to_valve
+            )));
+        }
+    }
     // Get shortest paths from all pairs of nodes
-    let mut condensed_paths: Vec<Vec<u8>> = floyd_warshall(&valves, &valve_indicies);
+    let mut condensed_paths: Vec<Vec<u8>> = shortest_paths(&valves, &valve_indicies);
 
     // Reduce valves to only relevant valves
     for i in irrelevant_valves.iter().rev() {
@@ -179,57 +201,55 @@ pub fn part_one(input: &str) -> Option<u32> {
         condensed_paths.remove(*i);
     }
 
+    // Opened valves are tracked as bits of a u16
+    if valves.len() > 16 {
+        return Err(SolveError::new(format!(
+            "expected at most 16 relevant valves, found {}",
+            valves.len()
+        )));
+    }
+
     // Get starting position
-    let (start_valve, _): (usize, &Valve) = valves
+    let start_valve: usize = valves
         .iter()
-        .enumerate()
-        .find(|(_, v)| v.name == "AA")
-        .unwrap();
+        .position(|v| v.name == "AA")
+        .ok_or("no starting valve AA")?;
+
+    Ok(Network {
+        valves,
+        condensed_paths,
+        start_valve,
+    })
+}
+
+pub fn part_one(network: &Network) -> Result<Answer, SolveError> {
+    const TIME_LIMIT: u8 = 30;
+    let Network {
+        valves,
+        condensed_paths,
+        start_valve,
+    } = network;
+
     // Find max pressure
     let max_pressure: u32 = traveling_salesman(
-        &valves,
-        &condensed_paths,
-        start_valve,
-        1 << start_valve,
+        valves,
+        condensed_paths,
+        *start_valve,
+        1 << *start_valve,
         TIME_LIMIT,
         &mut HashMap::new(),
     );
 
-    Some(max_pressure)
+    Ok(Answer::from(max_pressure))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    // Parse valves
-    let (_, mut valves): (&str, Vec<Valve>) = parse_network(input).unwrap();
+pub fn part_two(network: &Network) -> Result<Answer, SolveError> {
     const TIME_LIMIT: u8 = 26;
-
-    // Get indicies of valves and relevant valves
-    let mut valve_indicies: HashMap<&str, usize> = HashMap::with_capacity(valves.len());
-    let mut irrelevant_valves: Vec<usize> = Vec::new();
-    for (i, valve) in valves.iter().enumerate() {
-        valve_indicies.insert(&valve.name, i);
-        if valve.name != "AA" && valve.rate == 0 {
-            irrelevant_valves.push(i);
-        }
-    }
-    // Get shortest paths from all pairs of nodes
-    let mut condensed_paths: Vec<Vec<u8>> = floyd_warshall(&valves, &valve_indicies);
-
-    // Reduce valves to only relevant valves
-    for i in irrelevant_valves.iter().rev() {
-        valves.remove(*i);
-        for path in condensed_paths.iter_mut().rev() {
-            path.remove(*i);
-        }
-        condensed_paths.remove(*i);
-    }
-
-    // Get starting position
-    let (start_valve, _): (usize, &Valve) = valves
-        .iter()
-        .enumerate()
-        .find(|(_, v)| v.name == "AA")
-        .unwrap();
+    let Network {
+        valves,
+        condensed_paths,
+        start_valve,
+    } = network;
 
     // Cache
     let mut cache_states: HashMap<ValveState, u32> = HashMap::new();
@@ -240,17 +260,17 @@ pub fn part_two(input: &str) -> Option<u32> {
     let mut max_pressure: u32 = 0;
     for i in 1..(max_partitions / 2) {
         let me: u32 = traveling_salesman(
-            &valves,
-            &condensed_paths,
-            start_valve,
+            valves,
+            condensed_paths,
+            *start_valve,
             i,
             TIME_LIMIT,
             &mut cache_states,
         );
         let elephant: u32 = traveling_salesman(
-            &valves,
-            &condensed_paths,
-            start_valve,
+            valves,
+            condensed_paths,
+            *start_valve,
             max_partitions ^ i,
             TIME_LIMIT,
             &mut cache_states,
@@ -258,13 +278,27 @@ pub fn part_two(input: &str) -> Option<u32> {
         max_pressure = max_pressure.max(me + elephant);
     }
 
-    Some(max_pressure)
+    Ok(Answer::from(max_pressure))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 16);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Params = ();
+    type Model<'a> = Network;
+
+    fn parse(input: &str) -> Result<Network, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(network: &Network, _: &()) -> Result<Answer, SolveError> {
+        part_one(network)
+    }
+
+    fn part_two(network: &Network, _: &()) -> Result<Answer, SolveError> {
+        part_two(network)
+    }
 }
 
 #[cfg(test)]
@@ -273,13 +307,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 16);
-        assert_eq!(part_one(&input), Some(1651));
+        let input = crate::read_file("examples", 2022, 16);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(1651)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 16);
-        assert_eq!(part_two(&input), Some(1707));
+        let input = crate::read_file("examples", 2022, 16);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(1707)));
     }
 }
@@ -1,5 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::{Answer, Solution, SolveError};
+
 // Spawn rock with the coordinates
 fn spawn_rock(tallest_level: u64, rock_type: u8) -> Vec<(u64, u64)> {
     let spawn: (u64, u64) = (2, tallest_level + 3);
@@ -51,7 +53,19 @@ fn spawn_rock(tallest_level: u64, rock_type: u8) -> Vec<(u64, u64)> {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+// Jet pattern without the trailing newline
+pub fn parse(input: &str) -> Result<&[u8], SolveError> {
+    let jets: &[u8] = input.trim_end().as_bytes();
+
+    // Jets only push left or right
+    if jets.is_empty() || jets.iter().any(|c| !matches!(c, b'<' | b'>')) {
+        return Err(SolveError::new("expected a single line of '<' and '>'"));
+    }
+
+    Ok(jets)
+}
+
+pub fn part_one(jets: &[u8]) -> Result<Answer, SolveError> {
     // Width of chamber
     const WIDTH: u64 = 7;
     // Number of rocks to drop
@@ -68,15 +82,13 @@ pub fn part_one(input: &str) -> Option<u64> {
     let mut rock: Vec<(u64, u64)> = spawn_rock(tallest_level, rock_type);
     let mut new_rock: bool = false;
 
-    let input_vec: &[u8] = input.as_bytes();
-
     // Iterate over cycles
     let mut dropped_rocks: u16 = 0;
     let mut cycle: usize = 0;
     while dropped_rocks < ROCKS_TO_DROP {
         // Iterate cycle
-        let c: u8 = input_vec[cycle];
-        cycle = (cycle + 1) % (input_vec.len() - 1);
+        let c: u8 = jets[cycle];
+        cycle = (cycle + 1) % jets.len();
 
         // Jet of gas push rock horizontally
         let mut can_shift: bool = true;
@@ -145,7 +157,7 @@ pub fn part_one(input: &str) -> Option<u64> {
         }
     }
 
-    Some(tallest_level)
+    Ok(Answer::from(tallest_level))
 }
 
 // Width of chamber
@@ -164,7 +176,7 @@ struct RockStateRecord {
     times_seen: u8,
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(jets: &[u8]) -> Result<Answer, SolveError> {
     // Number of rocks to drop
     const ROCKS_TO_DROP: u64 = 1000000000000;
 
@@ -179,8 +191,6 @@ pub fn part_two(input: &str) -> Option<u64> {
     let mut rock: Vec<(u64, u64)> = spawn_rock(tallest_level, rock_type);
     let mut new_rock: bool = false;
 
-    let input_vec: &[u8] = input.as_bytes();
-
     // Iterate over cycles
     let mut dropped_rocks: u64 = 0;
     let mut cycle: usize = 0;
@@ -192,8 +202,8 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     while dropped_rocks < ROCKS_TO_DROP {
         // Iterate cycle
-        let c: u8 = input_vec[cycle];
-        cycle = (cycle + 1) % (input_vec.len() - 1);
+        let c: u8 = jets[cycle];
+        cycle = (cycle + 1) % jets.len();
 
         // Jet of gas push rock horizontally
         let mut can_shift: bool = true;
@@ -325,13 +335,27 @@ pub fn part_two(input: &str) -> Option<u64> {
         }
     }
 
-    Some(tallest_level + repeated_total_height)
+    Ok(Answer::from(tallest_level + repeated_total_height))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 17);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Params = ();
+    type Model<'a> = &'a [u8];
+
+    fn parse(input: &str) -> Result<&[u8], SolveError> {
+        parse(input)
+    }
+
+    fn part_one(jets: &&[u8], _: &()) -> Result<Answer, SolveError> {
+        part_one(jets)
+    }
+
+    fn part_two(jets: &&[u8], _: &()) -> Result<Answer, SolveError> {
+        part_two(jets)
+    }
 }
 
 #[cfg(test)]
@@ -340,13 +364,16 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 17);
-        assert_eq!(part_one(&input), Some(3068));
+        let input = crate::read_file("examples", 2022, 17);
+        assert_eq!(part_one(parse(&input).unwrap()), Ok(Answer::from(3068)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 17);
-        assert_eq!(part_two(&input), Some(1514285714288));
+        let input = crate::read_file("examples", 2022, 17);
+        assert_eq!(
+            part_two(parse(&input).unwrap()),
+            Ok(Answer::from(1514285714288_u64))
+        );
     }
 }
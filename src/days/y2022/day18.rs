@@ -0,0 +1,158 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use crate::{
+    graph::{bfs, Paths},
+    Answer, Solution, SolveError,
+};
+
+const ADJACENT_OFFSET: [(i16, i16, i16); 6] = [
+    (-1, 0, 0),
+    (1, 0, 0),
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1),
+];
+
+// Parse droplets
+pub fn parse(input: &str) -> Result<Vec<(i16, i16, i16)>, SolveError> {
+    input
+        .lines()
+        .map(|line| {
+            let coords: Vec<i16> = line
+                .split(',')
+                .map(|n| n.parse::<i16>())
+                .collect::<Result<_, _>>()
+                .map_err(|_| SolveError::at(input, line, "expected integer coordinates"))?;
+
+            match coords[..] {
+                [x, y, z] => Ok((x, y, z)),
+                _ => Err(SolveError::at(input, line, "expected 3 coordinates")),
+            }
+        })
+        .collect()
+}
+
+pub fn part_one(droplets: &[(i16, i16, i16)]) -> Result<Answer, SolveError> {
+    // Track surface area
+    let mut surface_area: u16 = 0;
+    // Record map of droplet coordinates
+    let mut grid: HashSet<(i16, i16, i16)> = HashSet::new();
+
+    for &(x, y, z) in droplets {
+        // Area to add
+        let mut added_surface: u16 = 6;
+
+        // Check the 6 adjacent sides
+        for (dx, dy, dz) in ADJACENT_OFFSET {
+            // If adjacent to another droplet
+            if grid.contains(&(x + dx, y + dy, z + dz)) {
+                surface_area -= 1;
+                added_surface -= 1;
+            }
+        }
+
+        grid.insert((x, y, z));
+        surface_area += added_surface;
+    }
+
+    Ok(Answer::from(surface_area))
+}
+
+pub fn part_two(droplets: &[(i16, i16, i16)]) -> Result<Answer, SolveError> {
+    // Bounds of the droplets coordinates
+    let mut x_bound: (i16, i16) = (i16::MAX, 0);
+    let mut y_bound: (i16, i16) = (i16::MAX, 0);
+    let mut z_bound: (i16, i16) = (i16::MAX, 0);
+
+    // Droplet coordinates
+    let grid: HashSet<(i16, i16, i16)> = droplets
+        .iter()
+        .map(|&(x, y, z)| {
+            x_bound = (x.min(x_bound.0), x.max(x_bound.1));
+            y_bound = (y.min(y_bound.0), y.max(y_bound.1));
+            z_bound = (z.min(z_bound.0), z.max(z_bound.1));
+
+            (x, y, z)
+        })
+        .collect();
+    // Expand box by 1 in case corner is filled
+    let x_range: RangeInclusive<i16> = (x_bound.0 - 1)..=(x_bound.1 + 1);
+    let y_range: RangeInclusive<i16> = (y_bound.0 - 1)..=(y_bound.1 + 1);
+    let z_range: RangeInclusive<i16> = (z_bound.0 - 1)..=(z_bound.1 + 1);
+
+    // Flood fill surrounding space of droplets, from a corner outside of them
+    let air_blocks: Paths<(i16, i16, i16), usize> = bfs(
+        [(*x_range.start(), *y_range.start(), *z_range.start())],
+        |&(x, y, z)| {
+            // Check the 6 adjacent sides
+            ADJACENT_OFFSET
+                .iter()
+                // Coord of adjacent block
+                .map(move |(dx, dy, dz)| (x + dx, y + dy, z + dz))
+                // Stay inside bound and skip blocks that are part of droplets
+                .filter(|adj_block| {
+                    x_range.contains(&adj_block.0)
+                        && y_range.contains(&adj_block.1)
+                        && z_range.contains(&adj_block.2)
+                        && !grid.contains(adj_block)
+                })
+        },
+    );
+
+    // Find air blocks that are adjacent to droplets and count the touching surfaces
+    let surface_area: u16 = air_blocks
+        .reached()
+        .map(|((ax, ay, az), _)| {
+            // Track touching surfaces of air and droplet
+            let mut touching_surfaces: u16 = 0;
+            // Check adjacent sides
+            for (dx, dy, dz) in ADJACENT_OFFSET {
+                if grid.contains(&(*ax + dx, *ay + dy, *az + dz)) {
+                    touching_surfaces += 1;
+                }
+            }
+
+            touching_surfaces
+        })
+        .sum::<u16>();
+
+    Ok(Answer::from(surface_area))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Params = ();
+    type Model<'a> = Vec<(i16, i16, i16)>;
+
+    fn parse(input: &str) -> Result<Vec<(i16, i16, i16)>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(droplets: &Vec<(i16, i16, i16)>, _: &()) -> Result<Answer, SolveError> {
+        part_one(droplets)
+    }
+
+    fn part_two(droplets: &Vec<(i16, i16, i16)>, _: &()) -> Result<Answer, SolveError> {
+        part_two(droplets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 18);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(64)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 18);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(58)));
+    }
+}
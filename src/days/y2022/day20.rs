@@ -1,5 +1,7 @@
 use std::{cell::RefCell, rc::Rc};
 
+use crate::{Answer, Solution, SolveError};
+
 // struct Node {
 //     value: i64,
 //     prev: Option<Rc<RefCell<Node>>>,
@@ -89,17 +91,35 @@ fn decrypt_file(file: &mut [Rc<RefCell<Node<i64>>>], num_mix: u8) -> i64 {
         .sum::<i64>()
 }
 
-pub fn part_one(input: &str) -> Option<i64> {
-    const KEY: i64 = 1;
-    const NUM_MIX: u8 = 1;
-
-    // Generate double linked list backed by a vector of references
-    let mut dll: Vec<Rc<RefCell<Node<i64>>>> = input
+// Encrypted file as list of numbers
+pub fn parse(input: &str) -> Result<Vec<i64>, SolveError> {
+    let numbers: Vec<i64> = input
         .lines()
+        .map(|n| {
+            n.parse::<i64>()
+                .map_err(|_| SolveError::at(input, n, "expected 64-bit integer"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    // Moves are taken modulo length - 1, and offsets are counted from 0
+    if numbers.len() < 2 {
+        return Err(SolveError::new("expected at least 2 numbers"));
+    }
+    if !numbers.contains(&0) {
+        return Err(SolveError::new("expected a 0 in the file"));
+    }
+
+    Ok(numbers)
+}
+
+// Generate double linked list backed by a vector of references
+fn generate_dll(numbers: &[i64], key: i64) -> Vec<Rc<RefCell<Node<i64>>>> {
+    let dll: Vec<Rc<RefCell<Node<i64>>>> = numbers
+        .iter()
         .enumerate()
         .map(|(i, n)| {
             Rc::new(RefCell::new(Node {
-                value: n.parse::<i64>().unwrap() * KEY,
+                value: n * key,
                 idx: i,
                 prev: if i == 0 { 0 } else { i - 1 },
                 next: i + 1,
@@ -111,38 +131,45 @@ pub fn part_one(input: &str) -> Option<i64> {
     // Set next of last element to wrap around the front
     dll[dll.len() - 1].borrow_mut().next = 0;
 
-    Some(decrypt_file(&mut dll, NUM_MIX))
+    dll
+}
+
+pub fn part_one(numbers: &[i64]) -> Result<Answer, SolveError> {
+    const KEY: i64 = 1;
+    const NUM_MIX: u8 = 1;
+
+    let mut dll: Vec<Rc<RefCell<Node<i64>>>> = generate_dll(numbers, KEY);
+
+    Ok(Answer::from(decrypt_file(&mut dll, NUM_MIX)))
 }
 
-pub fn part_two(input: &str) -> Option<i64> {
+pub fn part_two(numbers: &[i64]) -> Result<Answer, SolveError> {
     const KEY: i64 = 811589153;
     const NUM_MIX: u8 = 10;
 
-    // Generate double linked list backed by a vector of references
-    let mut dll: Vec<Rc<RefCell<Node<i64>>>> = input
-        .lines()
-        .enumerate()
-        .map(|(i, n)| {
-            Rc::new(RefCell::new(Node {
-                value: n.parse::<i64>().unwrap() * KEY,
-                idx: i,
-                prev: if i == 0 { 0 } else { i - 1 },
-                next: i + 1,
-            }))
-        })
-        .collect();
-    // Set prev of first element to wrap around the end
-    dll[0].borrow_mut().prev = dll.len() - 1;
-    // Set next of last element to wrap around the front
-    dll[dll.len() - 1].borrow_mut().next = 0;
+    let mut dll: Vec<Rc<RefCell<Node<i64>>>> = generate_dll(numbers, KEY);
 
-    Some(decrypt_file(&mut dll, NUM_MIX))
+    Ok(Answer::from(decrypt_file(&mut dll, NUM_MIX)))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 20);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Params = ();
+    type Model<'a> = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(numbers: &Vec<i64>, _: &()) -> Result<Answer, SolveError> {
+        part_one(numbers)
+    }
+
+    fn part_two(numbers: &Vec<i64>, _: &()) -> Result<Answer, SolveError> {
+        part_two(numbers)
+    }
 }
 
 #[cfg(test)]
@@ -151,13 +178,16 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 20);
-        assert_eq!(part_one(&input), Some(3));
+        let input = crate::read_file("examples", 2022, 20);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(3)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 20);
-        assert_eq!(part_two(&input), Some(1623178306));
+        let input = crate::read_file("examples", 2022, 20);
+        assert_eq!(
+            part_two(&parse(&input).unwrap()),
+            Ok(Answer::from(1623178306))
+        );
     }
 }
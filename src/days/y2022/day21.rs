@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+
+use crate::{Answer, Solution, SolveError};
+
+// Have string be tuple of chars bc of Copy issues
+#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug)]
+pub struct Variable {
+    name: (char, char, char, char),
+}
+
+impl Variable {
+    fn to_variable(input: &str) -> Variable {
+        let name: Vec<char> = input.chars().collect();
+        Variable {
+            name: (name[0], name[1], name[2], name[3]),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum Yell {
+    Value(f64),
+    Operation((Variable, bool), Op, (Variable, bool)),
+}
+
+// Variables are 4 lowercase letters
+fn is_variable(name: &str) -> bool {
+    name.len() == 4 && name.bytes().all(|c| c.is_ascii_lowercase())
+}
+
+pub fn parse(input: &str) -> Result<HashMap<Variable, Yell>, SolveError> {
+    let mut yells: HashMap<Variable, Yell> = HashMap::new();
+    // Names used in operations, checked once every monkey is known
+    let mut operands: Vec<&str> = Vec::new();
+
+    for line in input.lines() {
+        let invalid = || {
+            SolveError::at(
+                input,
+                line,
+                "expected \"name: number\" or \"name: name op name\"",
+            )
+        };
+
+        let (name, rhs): (&str, &str) = line.split_once(": ").ok_or_else(invalid)?;
+        if !is_variable(name) {
+            return Err(invalid());
+        }
+        let variable: Variable = Variable::to_variable(name);
+
+        let rhs_split: Vec<&str> = rhs.split(' ').collect();
+        match rhs_split[..] {
+            [value] => {
+                yells.insert(
+                    variable,
+                    Yell::Value(value.parse::<f64>().map_err(|_| invalid())?),
+                );
+            }
+            [lhs, op, rhs] if is_variable(lhs) && is_variable(rhs) => {
+                let op: Op = match op {
+                    "+" => Op::Add,
+                    "-" => Op::Sub,
+                    "*" => Op::Mul,
+                    "/" => Op::Div,
+                    _ => return Err(SolveError::at(input, op, "expected +, -, * or /")),
+                };
+                yells.insert(
+                    variable,
+                    Yell::Operation(
+                        (Variable::to_variable(lhs), false),
+                        op,
+                        (Variable::to_variable(rhs), false),
+                    ),
+                );
+                operands.extend([lhs, rhs]);
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    // Every operand must be yelled by some monkey
+    if let Some(operand) = operands
+        .iter()
+        .find(|operand| !yells.contains_key(&Variable::to_variable(operand)))
+    {
+        return Err(SolveError::at(
+            input,
+            operand,
+            format!("no monkey named {}", operand),
+        ));
+    }
+
+    // Both parts start from root
+    match yells.get(&Variable::to_variable("root")) {
+        Some(Yell::Operation(..)) => Ok(yells),
+        Some(Yell::Value(_)) => Err(SolveError::new("root is not an operation")),
+        None => Err(SolveError::new("no monkey named root")),
+    }
+}
+
+fn solve(lhs: f64, op: Op, rhs: f64) -> f64 {
+    match op {
+        Op::Mul => lhs * rhs,
+        Op::Div => lhs / rhs,
+        Op::Add => lhs + rhs,
+        Op::Sub => lhs - rhs,
+    }
+}
+
+fn evaluate(
+    yells: &mut HashMap<Variable, Yell>,
+    variable: &Variable,
+    part_two: bool,
+) -> Option<f64> {
+    // Signal recursive call that branch contains humn if part two
+    if part_two && variable == &Variable::to_variable("humn") {
+        return None;
+    }
+
+    // Recursively evaluate AST
+    let yell: Yell = yells.get(variable).copied().unwrap();
+    match yell {
+        Yell::Value(value) => Some(value),
+        Yell::Operation((lhs, _), op, (rhs, _)) => {
+            let lhs_yell: Option<f64> = evaluate(yells, &lhs, part_two);
+            let rhs_yell: Option<f64> = evaluate(yells, &rhs, part_two);
+
+            // If evaluate if doing part one
+            if let (Some(lhs_val), Some(rhs_val)) = (lhs_yell, rhs_yell) {
+                Some(solve(lhs_val, op, rhs_val))
+            } else {
+                // For part two, don't evaluate branch if it contains humn. Set true to denote branch contains humn.
+                if lhs_yell.is_none() {
+                    *yells.get_mut(variable).unwrap() =
+                        Yell::Operation((lhs, true), op, (rhs, false));
+                } else {
+                    *yells.get_mut(variable).unwrap() =
+                        Yell::Operation((lhs, false), op, (rhs, true));
+                }
+                None
+            }
+        }
+    }
+}
+
+fn reverse_op(op: Op) -> Op {
+    match op {
+        Op::Mul => Op::Div,
+        Op::Div => Op::Mul,
+        Op::Add => Op::Sub,
+        Op::Sub => Op::Add,
+    }
+}
+
+// Solving for humn only works if it appears on one side of each equation
+fn humn_on_both_sides() -> SolveError {
+    SolveError::new("humn appears on both sides of an equation")
+}
+
+fn eval_part_two(
+    mut root_val: f64,
+    yells: &mut HashMap<Variable, Yell>,
+    variable: &Variable,
+) -> Result<f64, SolveError> {
+    // Recursively eval branch that does not contain humn, and perform reverse operation on root.
+    // Then go down branch that does contains humn and repeat.
+    let yell: Yell = yells.get(variable).copied().unwrap();
+    match yell {
+        Yell::Value(_) => Ok(root_val),
+        Yell::Operation(lhs, op, rhs) => {
+            let (has_humn, no_humn): (&Variable, &Variable) = if lhs.1 {
+                (&lhs.0, &rhs.0)
+            } else {
+                (&rhs.0, &lhs.0)
+            };
+            let no_humn_val: f64 = evaluate(yells, no_humn, true).ok_or_else(humn_on_both_sides)?;
+
+            // Make sure solving for order
+            if (op == Op::Div || op == Op::Sub) && rhs.1 {
+                root_val = solve(no_humn_val, op, root_val);
+            } else {
+                root_val = solve(root_val, reverse_op(op), no_humn_val);
+            }
+
+            eval_part_two(root_val, yells, has_humn)
+        }
+    }
+}
+
+// Rounds a result to the integer it should be; floats can land just below or above it
+fn to_integer(value: f64) -> Result<i64, SolveError> {
+    let rounded: f64 = value.round();
+    // Allow for the rounding error of the operations, relative to the size of the value
+    if !value.is_finite() || (value - rounded).abs() > 1e-9 * rounded.abs().max(1_f64) {
+        return Err(SolveError::new(format!(
+            "expected an integer result, found {}",
+            value
+        )));
+    }
+    Ok(rounded as i64)
+}
+
+pub fn part_one(monkeys: &HashMap<Variable, Yell>) -> Result<Answer, SolveError> {
+    // Evaluating marks branches, so work on a copy
+    let mut yells: HashMap<Variable, Yell> = monkeys.clone();
+    let value: f64 = evaluate(&mut yells, &Variable::to_variable("root"), false)
+        .ok_or(SolveError::new("root could not be evaluated"))?;
+    Ok(Answer::from(to_integer(value)?))
+}
+
+pub fn part_two(monkeys: &HashMap<Variable, Yell>) -> Result<Answer, SolveError> {
+    // Evaluating marks branches, so work on a copy
+    let mut yells: HashMap<Variable, Yell> = monkeys.clone();
+
+    // The human has to be in the tree to solve for them
+    if !yells.contains_key(&Variable::to_variable("humn")) {
+        return Err(SolveError::new("no monkey named humn"));
+    }
+
+    let root: Variable = Variable::to_variable("root");
+    let Some(Yell::Operation((lhs, _), _, (rhs, _))) = yells.get(&root).copied() else {
+        return Err(SolveError::new("root is not an operation"));
+    };
+    let lhs_yell = evaluate(&mut yells, &lhs, true);
+    let rhs_yell = evaluate(&mut yells, &rhs, true);
+
+    let humn: f64 = if let Some(root_val) = lhs_yell {
+        eval_part_two(root_val, &mut yells, &rhs)?
+    } else {
+        let root_val: f64 = rhs_yell.ok_or_else(humn_on_both_sides)?;
+        eval_part_two(root_val, &mut yells, &lhs)?
+    };
+    Ok(Answer::from(to_integer(humn)?))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Params = ();
+    type Model<'a> = HashMap<Variable, Yell>;
+
+    fn parse(input: &str) -> Result<HashMap<Variable, Yell>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(monkeys: &HashMap<Variable, Yell>, _: &()) -> Result<Answer, SolveError> {
+        part_one(monkeys)
+    }
+
+    fn part_two(monkeys: &HashMap<Variable, Yell>, _: &()) -> Result<Answer, SolveError> {
+        part_two(monkeys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_integer() {
+        assert_eq!(to_integer(3.9999999999999), Ok(4));
+        assert_eq!(to_integer(3301.0000000001), Ok(3301));
+        assert_eq!(to_integer(-2.0), Ok(-2));
+        assert!(to_integer(2.5).is_err());
+        assert!(to_integer(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 21);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(152)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 21);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(301)));
+    }
+}
@@ -1,14 +1,24 @@
 use nom::{
     branch::alt,
-    character::complete::{self, alpha1},
+    character::complete::{self, multispace0, one_of},
+    combinator::eof,
+    error::context,
     multi::many1,
-    IResult, Parser,
+    sequence::{preceded, terminated},
+    Parser,
+};
+
+use crate::{
+    helpers::Grid,
+    parser::{parse_section, ParseResult},
+    Answer, Solution, SolveError,
 };
 
 #[derive(Debug)]
-enum Instruction {
+pub enum Instruction {
     Steps(u32),
-    Turn(char),
+    // -1 to turn left, 1 to turn right
+    Turn(i8),
 }
 
 #[derive(Debug)]
@@ -18,60 +28,80 @@ struct Me {
     direction: i8,
 }
 
-fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
-    let (input, instructions): (&str, Vec<Instruction>) = many1(alt((
-        (complete::u32).map(Instruction::Steps),
-        alpha1.map(|c: &str| Instruction::Turn(c.chars().next().unwrap())),
-    )))(input)?;
+fn parse_instructions(input: &str) -> ParseResult<'_, Vec<Instruction>> {
+    const EXPECTED: &str = "a number of steps or a turn L or R";
+
+    // The path is the rest of the input, so anything that stops it is an invalid instruction
+    let (input, instructions): (&str, Vec<Instruction>) = terminated(
+        many1(context(
+            EXPECTED,
+            alt((
+                (complete::u32).map(Instruction::Steps),
+                one_of("LR").map(|c: char| Instruction::Turn(if c == 'L' { -1 } else { 1 })),
+            )),
+        )),
+        context(EXPECTED, preceded(multispace0, eof)),
+    )(input)?;
 
     Ok((input, instructions))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let split: Vec<&str> = input.split("\n\n").collect();
-    // Parse map
-    let mut map: Vec<Vec<char>> = split[0]
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect();
+// Board map padded to a rectangle, and the path to follow
+pub struct Notes {
+    map: Grid<char>,
+    instructions: Vec<Instruction>,
+}
+
+pub fn parse(input: &str) -> Result<Notes, SolveError> {
+    let (map_str, path_str): (&str, &str) = input
+        .split_once("\n\n")
+        .ok_or("expected the map and the path separated by an empty line")?;
+
+    // Parse map, padded with space so it's a complete rectangle; the map starts the input
+    let map: Grid<char> =
+        Grid::parse_padded(map_str, ' ', |c| matches!(c, ' ' | '.' | '#').then_some(c))?;
+    if map.rows().any(|h_line| h_line.iter().all(|&c| c == ' ')) {
+        return Err(SolveError::new(
+            "expected every row of the map to have tiles",
+        ));
+    }
+
+    // Parse instructions
+    let instructions: Vec<Instruction> = parse_section(input, path_str, parse_instructions)?;
+
+    Ok(Notes { map, instructions })
+}
+
+pub fn part_one(notes: &Notes) -> Result<Answer, SolveError> {
+    let map: &Grid<char> = &notes.map;
 
     // Get the horizontal bounds of the map
     let horizontal_bounds: Vec<(usize, usize)> = map
-        .iter()
+        .rows()
         .map(|h_line| {
             (
                 h_line.iter().position(|&c| c != ' ').unwrap(),
-                h_line.len() - 1,
+                h_line.iter().rposition(|&c| c != ' ').unwrap(),
             )
         })
         .collect();
 
-    // Pad lines with space so it's a complete rectangle
-    let max_hor: usize = map.iter().map(|h_line| h_line.len()).max().unwrap();
-    for h_line in map.iter_mut() {
-        let start: usize = h_line.len();
-        for _ in start..max_hor {
-            h_line.push(' ');
-        }
-    }
     // Get the vertical bounds of the  map
-    let mut vertical_bounds: Vec<(usize, usize)> = Vec::with_capacity(map[0].len());
-    for i in 0..map[0].len() {
-        let mut start: Option<usize> = None;
-        let mut end: Option<usize> = None;
-
-        #[allow(clippy::needless_range_loop)]
-        for j in 0..map.len() {
-            if map[j][i] != ' ' {
-                if start.is_none() {
-                    start = Some(j);
-                } else {
-                    end = Some(j);
-                }
-            }
-        }
-        vertical_bounds.push((start.unwrap(), end.unwrap()));
-    }
+    let vertical_bounds: Vec<(usize, usize)> = map
+        .columns()
+        .map(|v_line| {
+            let tiles: Vec<usize> = v_line
+                .enumerate()
+                .filter(|(_, &c)| c != ' ')
+                .map(|(j, _)| j)
+                .collect();
+            // Columns without tiles are never walked into
+            (
+                tiles.first().copied().unwrap_or(0),
+                tiles.last().copied().unwrap_or(0),
+            )
+        })
+        .collect();
 
     let mut me: Me = Me {
         x: horizontal_bounds[0].0,
@@ -79,9 +109,7 @@ pub fn part_one(input: &str) -> Option<u32> {
         direction: 0,
     };
 
-    // Parse instructions
-    let (_, instructions): (&str, Vec<Instruction>) = parse_instructions(split[1]).unwrap();
-    for instr in &instructions {
+    for instr in &notes.instructions {
         match instr {
             Instruction::Steps(steps) => {
                 for _ in 0..*steps {
@@ -91,7 +119,7 @@ pub fn part_one(input: &str) -> Option<u32> {
                             if front > horizontal_bounds[me.y].1 {
                                 front = horizontal_bounds[me.y].0;
                             }
-                            if map[me.y][front] != '#' {
+                            if map[(front, me.y)] != '#' {
                                 me.x = front;
                             }
                         }
@@ -100,7 +128,7 @@ pub fn part_one(input: &str) -> Option<u32> {
                             if front > vertical_bounds[me.x].1 {
                                 front = vertical_bounds[me.x].0;
                             }
-                            if map[front][me.x] != '#' {
+                            if map[(me.x, front)] != '#' {
                                 me.y = front;
                             }
                         }
@@ -109,7 +137,7 @@ pub fn part_one(input: &str) -> Option<u32> {
                             if front < horizontal_bounds[me.y].0 as i16 {
                                 front = horizontal_bounds[me.y].1 as i16;
                             }
-                            if map[me.y][front as usize] != '#' {
+                            if map[(front as usize, me.y)] != '#' {
                                 me.x = front as usize;
                             }
                         }
@@ -118,27 +146,23 @@ pub fn part_one(input: &str) -> Option<u32> {
                             if front < vertical_bounds[me.x].0 as i16 {
                                 front = vertical_bounds[me.x].1 as i16;
                             }
-                            if map[front as usize][me.x] != '#' {
+                            if map[(me.x, front as usize)] != '#' {
                                 me.y = front as usize;
                             }
                         }
-                        _ => panic!("invalid dir: {:?}", me.direction),
+                        _ => unreachable!("direction is kept in 0..4"),
                     }
                 }
             }
             Instruction::Turn(dir) => {
-                match dir {
-                    'L' => me.direction -= 1,
-                    'R' => me.direction += 1,
-                    _ => panic!("Invalid direction: {:?}", dir),
-                }
-                me.direction = me.direction.rem_euclid(4);
+                me.direction = (me.direction + dir).rem_euclid(4);
             }
         }
     }
-    // dbg!(&me);
 
-    Some(1000 * (me.y + 1) as u32 + 4 * (me.x + 1) as u32 + me.direction as u32)
+    Ok(Answer::from(
+        1000 * (me.y + 1) as u32 + 4 * (me.x + 1) as u32 + me.direction as u32,
+    ))
 }
 
 // Too lazy to refactor part one
@@ -149,30 +173,23 @@ struct MeCube {
     direction: i8,
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let split: Vec<&str> = input.split("\n\n").collect();
-    let mut map: Vec<Vec<char>> = split[0]
-        .lines()
-        .map(|line| line.chars().collect())
-        .collect();
-    // Pad lines with space so it's a complete rectangle
-    let max_hor: usize = map.iter().map(|h_line| h_line.len()).max().unwrap();
-    for h_line in map.iter_mut() {
-        let start: usize = h_line.len();
-        for _ in start..max_hor {
-            h_line.push(' ');
-        }
+pub fn part_two(notes: &Notes) -> Result<Answer, SolveError> {
+    let map: &Grid<char> = &notes.map;
+
+    // The wrapping rules below are hard-coded for the real input's cube net
+    if map.height() != 200 || map.width() != 150 {
+        return Err(SolveError::new(
+            "expected a 150x200 map folded like the real input",
+        ));
     }
 
     let mut me: MeCube = MeCube {
-        x: map[0].iter().position(|&c| c != ' ').unwrap() as i16,
+        x: map.row(0).iter().position(|&c| c != ' ').unwrap() as i16,
         y: 0,
         direction: 0,
     };
 
-    // Parse instructions
-    let (_, instructions): (&str, Vec<Instruction>) = parse_instructions(split[1]).unwrap();
-    for instr in &instructions {
+    for instr in &notes.instructions {
         match instr {
             Instruction::Steps(steps) => {
                 for _ in 0..*steps {
@@ -277,11 +294,11 @@ pub fn part_two(input: &str) -> Option<u32> {
                                 new_dir = 0;
                             }
                         }
-                        _ => panic!("invalid dir: {:?}", me.direction),
+                        _ => unreachable!("direction is kept in 0..4"),
                     }
 
                     // If front is not blocked, take step
-                    if map[y_front as usize][x_front as usize] == '.' {
+                    if map[(x_front as usize, y_front as usize)] == '.' {
                         me.x = x_front;
                         me.y = y_front;
                         me.direction = new_dir;
@@ -289,23 +306,34 @@ pub fn part_two(input: &str) -> Option<u32> {
                 }
             }
             Instruction::Turn(dir) => {
-                match dir {
-                    'L' => me.direction -= 1,
-                    'R' => me.direction += 1,
-                    _ => panic!("Invalid direction: {:?}", dir),
-                }
-                me.direction = me.direction.rem_euclid(4);
+                me.direction = (me.direction + dir).rem_euclid(4);
             }
         }
     }
 
-    Some(1000 * (me.y + 1) as u32 + 4 * (me.x + 1) as u32 + me.direction as u32)
+    Ok(Answer::from(
+        1000 * (me.y + 1) as u32 + 4 * (me.x + 1) as u32 + me.direction as u32,
+    ))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 22);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Params = ();
+    type Model<'a> = Notes;
+
+    fn parse(input: &str) -> Result<Notes, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(notes: &Notes, _: &()) -> Result<Answer, SolveError> {
+        part_one(notes)
+    }
+
+    fn part_two(notes: &Notes, _: &()) -> Result<Answer, SolveError> {
+        part_two(notes)
+    }
 }
 
 #[cfg(test)]
@@ -314,13 +342,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 22);
-        assert_eq!(part_one(&input), Some(6032));
+        let input = crate::read_file("examples", 2022, 22);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(6032)));
     }
 
     #[test]
     fn test_part_two() {
-        let _input = advent_of_code::read_file("examples", 22);
-        // assert_eq!(part_two(&input), Some(5031));
+        let _input = crate::read_file("examples", 2022, 22);
+        // assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(5031)));
     }
 }
@@ -1,16 +1,27 @@
 use std::collections::{HashMap, HashSet};
 
-fn parse_input(input: &str) -> HashSet<(i32, i32)> {
-    let mut elves: HashSet<(i32, i32)> = HashSet::new();
-    for (y, line) in input.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            if c == '#' {
-                elves.insert((x as i32, y as i32));
-            }
-        }
+use crate::{helpers::Grid, Answer, Solution, SolveError};
+
+pub fn parse(input: &str) -> Result<HashSet<(i32, i32)>, SolveError> {
+    // Scan of the grove, true where an elf stands
+    let scan: Grid<bool> = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    // Elves spread beyond the scan, so they are tracked by position
+    let elves: HashSet<(i32, i32)> = scan
+        .iter()
+        .filter(|(_, &elf)| elf)
+        .map(|((x, y), _)| (x as i32, y as i32))
+        .collect();
+
+    // The empty ground is measured around the elves
+    if elves.is_empty() {
+        return Err(SolveError::new("expected at least one elf"));
     }
 
-    elves
+    Ok(elves)
 }
 
 fn propose_move(elves: &HashSet<(i32, i32)>, elf: (i32, i32), mut cycle: u8) -> (i32, i32) {
@@ -79,9 +90,9 @@ fn propose_move(elves: &HashSet<(i32, i32)>, elf: (i32, i32), mut cycle: u8) ->
     (x, y)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(grove: &HashSet<(i32, i32)>) -> Result<Answer, SolveError> {
     // Initial positions of the elves
-    let mut elves: HashSet<(i32, i32)> = parse_input(input);
+    let mut elves: HashSet<(i32, i32)> = grove.clone();
     let size: usize = elves.len();
 
     // Rounds
@@ -135,12 +146,14 @@ pub fn part_one(input: &str) -> Option<u32> {
         max_y = max_y.max(*y);
     }
 
-    Some(((max_x - min_x) as u32 + 1) * ((max_y - min_y) as u32 + 1) - elves.len() as u32)
+    Ok(Answer::from(
+        ((max_x - min_x) as u32 + 1) * ((max_y - min_y) as u32 + 1) - elves.len() as u32,
+    ))
 }
 
-pub fn part_two(input: &str) -> Option<u16> {
+pub fn part_two(grove: &HashSet<(i32, i32)>) -> Result<Answer, SolveError> {
     // Initial positions of the elves
-    let mut elves: HashSet<(i32, i32)> = parse_input(input);
+    let mut elves: HashSet<(i32, i32)> = grove.clone();
     let size: usize = elves.len();
 
     // Rounds
@@ -189,13 +202,27 @@ pub fn part_two(input: &str) -> Option<u16> {
         }
     }
 
-    Some(rounds)
+    Ok(Answer::from(rounds))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 23);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Params = ();
+    type Model<'a> = HashSet<(i32, i32)>;
+
+    fn parse(input: &str) -> Result<HashSet<(i32, i32)>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(grove: &HashSet<(i32, i32)>, _: &()) -> Result<Answer, SolveError> {
+        part_one(grove)
+    }
+
+    fn part_two(grove: &HashSet<(i32, i32)>, _: &()) -> Result<Answer, SolveError> {
+        part_two(grove)
+    }
 }
 
 #[cfg(test)]
@@ -204,13 +231,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 23);
-        assert_eq!(part_one(&input), Some(110));
+        let input = crate::read_file("examples", 2022, 23);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(110)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 23);
-        assert_eq!(part_two(&input), Some(20));
+        let input = crate::read_file("examples", 2022, 23);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(20)));
     }
 }
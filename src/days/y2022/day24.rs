@@ -0,0 +1,198 @@
+use crate::{
+    graph::{astar, Path},
+    helpers::{Grid, Pos},
+    Answer, Solution, SolveError,
+};
+
+// Maps of every minute until the blizzards repeat, with the start and goal positions
+pub struct Valley {
+    maps: Vec<Grid<u8>>,
+    start: Pos,
+    goal: Pos,
+}
+
+pub fn parse(input: &str) -> Result<Valley, SolveError> {
+    // 2d bit representation map
+    let initial_map: Grid<u8> = Grid::parse(input, |c| {
+        match c {
+            '.' => Some(0),      // empty
+            '#' => Some(1 << 0), // wall
+            '^' => Some(1 << 1), // north
+            'v' => Some(1 << 2), // south
+            '>' => Some(1 << 3), // east
+            '<' => Some(1 << 4), // west
+            _ => None,
+        }
+    })?;
+
+    // Blizzards wrap inside the walls
+    if initial_map.height() < 3 || initial_map.width() < 3 {
+        return Err(SolveError::new(format!(
+            "expected at least 3x3 tiles, found {}x{}",
+            initial_map.width(),
+            initial_map.height()
+        )));
+    }
+
+    // Get current and goal position
+    let start: Pos = (
+        initial_map
+            .row(0)
+            .iter()
+            .position(|&c| c == 0)
+            .ok_or("no opening in the top wall")?,
+        0,
+    );
+    let goal: Pos = (
+        initial_map
+            .row(initial_map.height() - 1)
+            .iter()
+            .position(|&c| c == 0)
+            .ok_or("no opening in the bottom wall")?,
+        initial_map.height() - 1,
+    );
+
+    // Blizzards are back where they started after a multiple of both inner sides
+    let period: usize = lcm(initial_map.width() - 2, initial_map.height() - 2);
+    let mut maps: Vec<Grid<u8>> = Vec::with_capacity(period);
+    maps.push(initial_map);
+    while maps.len() < period {
+        maps.push(increment_map(&maps[maps.len() - 1]));
+    }
+
+    Ok(Valley { maps, start, goal })
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+fn increment_map(map: &Grid<u8>) -> Grid<u8> {
+    // Write moves onto new map bc
+    let mut new_map: Grid<u8> = Grid::new(map.width(), map.height(), 0);
+    let y_end: usize = map.height() - 2;
+    let x_end: usize = map.width() - 2;
+    for ((x, y), state) in map.iter() {
+        // wall or empty; otherwise blizzard
+        if *state == 0 || *state == 1 {
+            new_map[(x, y)] |= state;
+        } else {
+            // north
+            if 2 & state != 0 {
+                let new_y: usize = if y == 1 { y_end } else { y - 1 };
+                new_map[(x, new_y)] |= 2;
+            }
+            // south
+            if 4 & state != 0 {
+                let new_y: usize = if y == y_end { 1 } else { y + 1 };
+                new_map[(x, new_y)] |= 4;
+            }
+            // east
+            if 8 & state != 0 {
+                let new_x: usize = if x == x_end { 1 } else { x + 1 };
+                new_map[(new_x, y)] |= 8;
+            }
+            // west
+            if 16 & state != 0 {
+                let new_x: usize = if x == 1 { x_end } else { x - 1 };
+                new_map[(new_x, y)] |= 16;
+            }
+        }
+    }
+
+    new_map
+}
+
+// Minutes to get from start to goal when setting off at minute `time`
+fn path_find(valley: &Valley, start: Pos, goal: Pos, time: usize) -> Option<usize> {
+    let period: usize = valley.maps.len();
+
+    // States are positions at a minute of the blizzard cycle
+    let path: Path<(Pos, usize), usize> = astar(
+        [(start, time % period)],
+        |&(pos, minute)| {
+            // Blizzards move first
+            let minute: usize = (minute + 1) % period;
+            let map: &Grid<u8> = &valley.maps[minute];
+
+            // move to an empty neighbour or stand still
+            map.neighbours4(pos)
+                .chain([pos])
+                .filter(move |&next| map[next] == 0)
+                .map(move |next| ((next, minute), 1))
+        },
+        // Manhattan distance drops by at most one per minute, so it is a consistent estimate
+        |&(pos, _)| pos.0.abs_diff(goal.0) + pos.1.abs_diff(goal.1),
+        |&(pos, _)| pos == goal,
+    )?;
+
+    Some(path.cost)
+}
+
+// Blizzards can block every way through the valley
+fn no_path() -> SolveError {
+    SolveError::new("no path through the blizzards")
+}
+
+pub fn part_one(valley: &Valley) -> Result<Answer, SolveError> {
+    let time: usize = path_find(valley, valley.start, valley.goal, 0).ok_or_else(no_path)?;
+
+    Ok(Answer::from(time))
+}
+
+pub fn part_two(valley: &Valley) -> Result<Answer, SolveError> {
+    let Valley { start, goal, .. } = *valley;
+
+    // start -> goal -> start -> goal
+    let mut total_time: usize = 0;
+    total_time += path_find(valley, start, goal, total_time).ok_or_else(no_path)?;
+    total_time += path_find(valley, goal, start, total_time).ok_or_else(no_path)?;
+    total_time += path_find(valley, start, goal, total_time).ok_or_else(no_path)?;
+
+    Ok(Answer::from(total_time))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Params = ();
+    type Model<'a> = Valley;
+
+    fn parse(input: &str) -> Result<Valley, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(valley: &Valley, _: &()) -> Result<Answer, SolveError> {
+        part_one(valley)
+    }
+
+    fn part_two(valley: &Valley, _: &()) -> Result<Answer, SolveError> {
+        part_two(valley)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 24);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(Answer::from(18)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 24);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(Answer::from(54)));
+    }
+}
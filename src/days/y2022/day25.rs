@@ -0,0 +1,120 @@
+use crate::{Answer, Solution, SolveError};
+
+fn decimal_to_snafu(mut decimal: u64) -> String {
+    let mut snafu_bfive: Vec<u8> = Vec::new();
+    while decimal != 0 {
+        snafu_bfive.push((decimal % 5) as u8);
+        decimal /= 5;
+    }
+
+    // example: 4890 base10 -> 124030 base5
+    // 124030 -> 1241=0 -> 12-1=0 -> 13-1=0 -> 2=-1=0
+    // right to left; if greater than 2, wrap around to -2, -1 and increment next digit; otherwise, keep
+    let mut snafu: Vec<char> = Vec::with_capacity(snafu_bfive.len() * 2);
+    let mut carry: u8 = 0;
+    for d in snafu_bfive.iter_mut() {
+        // Add the carry from prev snafu digit
+        *d += carry;
+
+        carry = u8::from(*d >= 3);
+        match d {
+            (0..=2) => {
+                snafu.push((*d + b'0') as char);
+            }
+            3 => {
+                snafu.push('=');
+            }
+            4 => {
+                snafu.push('-');
+            }
+            _ => {
+                snafu.push(((*d % 5) + b'0') as char);
+            }
+        }
+    }
+    // Edge case
+    if carry > 0 {
+        snafu.push((carry + b'0') as char);
+    }
+
+    snafu.iter().rev().collect()
+}
+
+fn snafu_to_decimal(input: &str, snafu: &str) -> Result<u64, SolveError> {
+    let mut decimal: i64 = 0;
+    for (place, (i, snafu_digit)) in snafu.char_indices().rev().enumerate() {
+        let digit: i64 = match snafu_digit {
+            snafu_digit if (['0', '1', '2']).contains(&snafu_digit) => {
+                (snafu_digit as u8 - b'0') as i64
+            }
+            '-' => -1,
+            '=' => -2,
+            _ => {
+                return Err(SolveError::at(
+                    input,
+                    &snafu[i..],
+                    format!("invalid snafu digit '{}'", snafu_digit),
+                ))
+            }
+        };
+        decimal += digit * 5_i64.pow(place as u32);
+    }
+    Ok(decimal as u64)
+}
+
+// Fuel requirements in decimal
+pub fn parse(input: &str) -> Result<Vec<u64>, SolveError> {
+    input
+        .lines()
+        .map(|line| snafu_to_decimal(input, line))
+        .collect()
+}
+
+pub fn part_one(fuel: &[u64]) -> Result<Answer, SolveError> {
+    let sum: u64 = fuel.iter().sum::<u64>();
+    Ok(Answer::from(decimal_to_snafu(sum)))
+}
+
+pub fn part_two(_fuel: &[u64]) -> Result<Answer, SolveError> {
+    Err(SolveError::Unsolved)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Params = ();
+    type Model<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>, SolveError> {
+        parse(input)
+    }
+
+    fn part_one(fuel: &Vec<u64>, _: &()) -> Result<Answer, SolveError> {
+        part_one(fuel)
+    }
+
+    fn part_two(fuel: &Vec<u64>, _: &()) -> Result<Answer, SolveError> {
+        part_two(fuel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2022, 25);
+        assert_eq!(
+            part_one(&parse(&input).unwrap()),
+            Ok(Answer::from(String::from("2=-1=0")))
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2022, 25);
+        assert_eq!(part_two(&parse(&input).unwrap()), Err(SolveError::Unsolved));
+    }
+}
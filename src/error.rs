@@ -0,0 +1,210 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+
+use crate::solution::part_label;
+
+/// A position in the input. Both numbers count from 1; columns count characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// The location of the byte `offset` in `input`.
+    pub fn of_offset(input: &str, offset: usize) -> Self {
+        let before = &input[..offset.min(input.len())];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// The location where `at` starts, if it is a slice of `input`.
+    pub fn of_slice(input: &str, at: &str) -> Option<Self> {
+        offset_of(input, at).map(|offset| Location::of_offset(input, offset))
+    }
+}
+
+// Byte offset of `at` in `input`, if it is a slice of it.
+fn offset_of(input: &str, at: &str) -> Option<usize> {
+    let start = input.as_ptr() as usize;
+    let position = at.as_ptr() as usize;
+
+    (start..=start + input.len())
+        .contains(&position)
+        .then(|| position - start)
+}
+
+// The line of `input` that contains the byte `offset`, without its line break.
+fn line_at(input: &str, offset: usize) -> &str {
+    let offset = offset.min(input.len());
+    let start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let end = input[offset..]
+        .find('\n')
+        .map_or(input.len(), |i| offset + i);
+
+    input[start..end].trim_end_matches('\r')
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Why a part did not produce an answer. Parsing fails with the same error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    /// The part has no solution yet.
+    Unsolved,
+    /// The input does not fit the puzzle, e.g. an unexpected character or a missing start.
+    Invalid {
+        message: String,
+        location: Option<Location>,
+        /// The input line at `location`, shown by [`SolveError::snippet`].
+        source_line: Option<String>,
+    },
+}
+
+impl SolveError {
+    /// An invalid input without a known location.
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError::Invalid {
+            message: message.into(),
+            location: None,
+            source_line: None,
+        }
+    }
+
+    /// An invalid input at `at`, which should be a slice of `input` such as a line or token.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        match offset_of(input, at) {
+            Some(offset) => SolveError::at_offset(input, offset, message),
+            None => SolveError::new(message),
+        }
+    }
+
+    /// An invalid input at the byte `offset` in `input`.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        SolveError::Invalid {
+            message: message.into(),
+            location: Some(Location::of_offset(input, offset)),
+            source_line: Some(line_at(input, offset).to_string()),
+        }
+    }
+
+    /// The input line of a located error with a caret under its column, e.g.
+    ///
+    /// ```text
+    ///   |
+    /// 2 | R x
+    ///   |   ^
+    /// ```
+    pub fn snippet(&self) -> Option<String> {
+        let SolveError::Invalid {
+            location: Some(location),
+            source_line: Some(source_line),
+            ..
+        } = self
+        else {
+            return None;
+        };
+        let gutter = " ".repeat(location.line.to_string().len());
+
+        Some(format!(
+            "{} |\n{} | {}\n{} | {}^",
+            gutter,
+            location.line,
+            source_line,
+            gutter,
+            " ".repeat(location.column - 1)
+        ))
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Unsolved => write!(f, "not solved"),
+            SolveError::Invalid {
+                message,
+                location: Some(location),
+                ..
+            } => write!(f, "{}: {}", location, message),
+            SolveError::Invalid {
+                message,
+                location: None,
+                ..
+            } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<String> for SolveError {
+    fn from(message: String) -> Self {
+        SolveError::new(message)
+    }
+}
+
+impl From<&str> for SolveError {
+    fn from(message: &str) -> Self {
+        SolveError::new(message)
+    }
+}
+
+/// A [`SolveError`] with the day and phase it happened in, as reported by the runner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayError {
+    pub day: u8,
+    /// The part that failed, or [`crate::solution::PARSE`].
+    pub part: u8,
+    pub error: SolveError,
+}
+
+impl Display for DayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} failed: {}", part_label(self.part), self.error)
+    }
+}
+
+impl std::error::Error for DayError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let input = "1000\n2x00\n\n3000";
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(
+            Location::of_slice(input, &line[1..]),
+            Some(Location { line: 2, column: 2 })
+        );
+        assert_eq!(
+            Location::of_offset(input, 0),
+            Location { line: 1, column: 1 }
+        );
+        assert_eq!(
+            Location::of_offset(input, 11),
+            Location { line: 4, column: 1 }
+        );
+        assert_eq!(Location::of_slice(input, &String::from("2x00")), None);
+
+        let error = SolveError::at(input, &line[1..], "expected digit, found 'x'");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected digit, found 'x'"
+        );
+        assert_eq!(error.snippet().unwrap(), "  |\n2 | 2x00\n  |  ^");
+        assert_eq!(SolveError::from("no start").to_string(), "no start");
+    }
+}
@@ -0,0 +1,380 @@
+/*
+ * Graph searches for solutions. The graph is never built: searches ask a `neighbours` closure
+ * for the nodes next to the one they are at, so nodes can be grid positions, states or indices.
+ * Example import from this file: `use advent_of_code::graph::bfs;`.
+ */
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Cost of an edge, such as `u32`. `Default` is a cost of zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// Path found by a search, from its start to its goal.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Path<N, C> {
+    /// Total cost of the edges along the path.
+    pub cost: C,
+    /// Nodes along the path, including the start and the goal.
+    pub nodes: Vec<N>,
+}
+
+/// Every node a search reached, with its cost from the nearest start and the edge it was reached by.
+pub struct Paths<N, C> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    costs: Vec<C>,
+    previous: Vec<Option<usize>>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Paths<N, C> {
+    fn new() -> Self {
+        Paths {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            costs: Vec::new(),
+            previous: Vec::new(),
+        }
+    }
+
+    // Records a node seen for the first time, returns its index
+    fn insert(&mut self, node: N, cost: C, previous: Option<usize>) -> usize {
+        let i: usize = self.nodes.len();
+        self.index.insert(node.clone(), i);
+        self.nodes.push(node);
+        self.costs.push(cost);
+        self.previous.push(previous);
+        i
+    }
+
+    // Nodes from the start to node `i`
+    fn path_at(&self, i: usize) -> Path<N, C> {
+        let mut nodes: Vec<N> = vec![self.nodes[i].clone()];
+        let mut at: usize = i;
+        while let Some(previous) = self.previous[at] {
+            nodes.push(self.nodes[previous].clone());
+            at = previous;
+        }
+        nodes.reverse();
+
+        Path {
+            cost: self.costs[i],
+            nodes,
+        }
+    }
+
+    /// Whether the search reached `node`.
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    /// Cost of the cheapest path to `node`, `None` if it was not reached.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.index.get(node).map(|&i| self.costs[i])
+    }
+
+    /// Cheapest path to `node`, `None` if it was not reached.
+    pub fn path_to(&self, node: &N) -> Option<Path<N, C>> {
+        self.index.get(node).map(|&i| self.path_at(i))
+    }
+
+    /// Reached nodes with their costs, in the order they were found.
+    pub fn reached(&self) -> impl Iterator<Item = (&N, C)> + '_ {
+        self.nodes.iter().zip(self.costs.iter().copied())
+    }
+
+    /// Number of reached nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+/// Breadth-first search from `starts` over edges of cost 1, until every reachable node is found.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    breadth_first(starts, neighbours, |_| false).0
+}
+
+/// Like [`bfs`], but stops at the first node that `is_goal`, which is the nearest one.
+pub fn bfs_to<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let (paths, goal) = breadth_first(starts, neighbours, is_goal);
+    goal.map(|i| paths.path_at(i))
+}
+
+/// Dijkstra's algorithm from `starts`, where `neighbours` gives the next nodes with the cost of the
+/// edge to them, until every reachable node is found.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(starts, neighbours, |_| C::default(), |_| false).0
+}
+
+/// Like [`dijkstra`], but stops at the first node that `is_goal`, which is the cheapest to reach.
+pub fn dijkstra_to<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (paths, goal) = best_first(starts, neighbours, |_| C::default(), is_goal);
+    goal.map(|i| paths.path_at(i))
+}
+
+/// A* search for the cheapest path to a node that `is_goal`. `heuristic` estimates the remaining
+/// cost from a node and must be consistent: it never drops by more than the cost of the edge
+/// taken, and is 0 at a goal, e.g. the manhattan distance on a grid of unit steps. Nodes are not
+/// revisited once expanded, so an estimate that is only admissible can miss the cheapest path.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (paths, goal) = best_first(starts, neighbours, heuristic, is_goal);
+    goal.map(|i| paths.path_at(i))
+}
+
+/// Floyd-Warshall over nodes `0..len`, where `neighbours` gives the next nodes with the cost of the
+/// edge to them. `costs[from][to]` is the cost of the cheapest path, `None` if there is none.
+pub fn all_pairs<C, I>(len: usize, mut neighbours: impl FnMut(usize) -> I) -> Vec<Vec<Option<C>>>
+where
+    C: Cost,
+    I: IntoIterator<Item = (usize, C)>,
+{
+    let mut costs: Vec<Vec<Option<C>>> = vec![vec![None; len]; len];
+    for (from, row) in costs.iter_mut().enumerate() {
+        row[from] = Some(C::default());
+        for (to, cost) in neighbours(from) {
+            row[to] = Some(row[to].map_or(cost, |known| known.min(cost)));
+        }
+    }
+
+    for k in 0..len {
+        // Paths through `k` do not make paths from `k` any cheaper, so its row stays as it is
+        let from_k: Vec<Option<C>> = costs[k].clone();
+        for row in costs.iter_mut() {
+            let Some(to_k) = row[k] else {
+                continue;
+            };
+            for (cost, from_k) in row.iter_mut().zip(&from_k) {
+                if let Some(from_k) = from_k {
+                    let through_k: C = to_k + *from_k;
+                    if cost.is_none_or(|direct| through_k < direct) {
+                        *cost = Some(through_k);
+                    }
+                }
+            }
+        }
+    }
+
+    costs
+}
+
+// Breadth-first search; returns the index of the goal if one was found
+fn breadth_first<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Paths<N, usize>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths: Paths<N, usize> = Paths::new();
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    for start in starts {
+        if !paths.contains(&start) {
+            queue.push_back(paths.insert(start, 0, None));
+        }
+    }
+
+    while let Some(i) = queue.pop_front() {
+        if is_goal(&paths.nodes[i]) {
+            return (paths, Some(i));
+        }
+
+        let cost: usize = paths.costs[i] + 1;
+        for next in neighbours(&paths.nodes[i]) {
+            if !paths.contains(&next) {
+                queue.push_back(paths.insert(next, cost, Some(i)));
+            }
+        }
+    }
+
+    (paths, None)
+}
+
+// Dijkstra's algorithm, or A* with a heuristic; returns the index of the goal if one was found
+fn best_first<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Paths<N, C>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths: Paths<N, C> = Paths::new();
+    // Nodes whose cost is final
+    let mut done: Vec<bool> = Vec::new();
+    // Min heap of estimated total cost and node
+    let mut queue: BinaryHeap<Reverse<(C, usize)>> = BinaryHeap::new();
+    for start in starts {
+        if !paths.contains(&start) {
+            let estimate: C = heuristic(&start);
+            queue.push(Reverse((estimate, paths.insert(start, C::default(), None))));
+            done.push(false);
+        }
+    }
+
+    while let Some(Reverse((_, i))) = queue.pop() {
+        // Skip nodes that were queued again with a lower cost
+        if done[i] {
+            continue;
+        }
+        done[i] = true;
+
+        if is_goal(&paths.nodes[i]) {
+            return (paths, Some(i));
+        }
+
+        for (next, step) in neighbours(&paths.nodes[i]) {
+            let cost: C = paths.costs[i] + step;
+            let j: usize = match paths.index.get(&next) {
+                Some(&j) if done[j] || paths.costs[j] <= cost => continue,
+                Some(&j) => {
+                    paths.costs[j] = cost;
+                    paths.previous[j] = Some(i);
+                    j
+                }
+                None => {
+                    done.push(false);
+                    paths.insert(next.clone(), cost, Some(i))
+                }
+            };
+            queue.push(Reverse((cost + heuristic(&next), j)));
+        }
+    }
+
+    (paths, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Weighted edges of a small graph: 0 -> 1 -> 3 is cheaper than 0 -> 3
+    fn edges(node: &u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 4), (3, 7)],
+            1 => vec![(3, 2)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let paths: Paths<u8, usize> = bfs([0], |&n| edges(&n).into_iter().map(|(to, _)| to));
+        assert_eq!(paths.len(), 4);
+        assert_eq!(paths.cost(&3), Some(1));
+        assert_eq!(paths.path_to(&3).unwrap().nodes, vec![0, 3]);
+        assert_eq!(paths.cost(&4), None);
+
+        let path = bfs_to(
+            [1, 2],
+            |&n| edges(&n).into_iter().map(|(to, _)| to),
+            |&n| n == 3,
+        );
+        assert_eq!(path.map(|path| path.nodes), Some(vec![1, 3]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths: Paths<u8, u32> = dijkstra([0], edges);
+        assert_eq!(paths.cost(&3), Some(3));
+        assert_eq!(paths.path_to(&3).unwrap().nodes, vec![0, 1, 3]);
+
+        let path: Path<u8, u32> = dijkstra_to([0], edges, |&n| n == 3).unwrap();
+        assert_eq!(
+            path,
+            Path {
+                cost: 3,
+                nodes: vec![0, 1, 3]
+            }
+        );
+
+        assert_eq!(dijkstra_to([1], edges, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Walk a 10x10 grid from a corner to the opposite one
+        let goal: (i32, i32) = (9, 9);
+        let path: Path<(i32, i32), u32> = astar(
+            [(0, 0)],
+            |&(x, y)| {
+                [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                    .into_iter()
+                    .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                    .map(|pos| (pos, 1))
+            },
+            |&(x, y)| (goal.0 - x + goal.1 - y) as u32,
+            |&pos| pos == goal,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.nodes.len(), 19);
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let costs: Vec<Vec<Option<u32>>> = all_pairs(4, |n| {
+            edges(&(n as u8))
+                .into_iter()
+                .map(|(to, cost)| (to as usize, cost))
+        });
+        assert_eq!(costs[0], vec![Some(0), Some(1), Some(4), Some(3)]);
+        assert_eq!(costs[3][0], None);
+    }
+}
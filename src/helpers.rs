@@ -2,3 +2,409 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::SolveError;
+
+/// Position in a [`Grid`] as `(x, y)`, where `x` is the column and `y` the row from the top.
+pub type Pos = (usize, usize);
+
+/// Steps to the 4 orthogonal neighbours as `(dx, dy)`: up, right, down, left.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Steps to the 8 surrounding neighbours as `(dx, dy)`, clockwise from up.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Rectangular 2D grid stored row by row. Always has at least one row and one column.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` x `height` cells, all set to `fill`. Panics if either is 0.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            width > 0 && height > 0,
+            "{}x{} grid has no cells",
+            width,
+            height
+        );
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Grid from equally long rows, `None` if their lengths differ or there are no cells.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height: usize = rows.len();
+        let width: usize = rows.first().map_or(0, Vec::len);
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a character map with one row per line, turning each character into a cell with
+    /// `tile`. Rows must be equally long, and characters `tile` returns `None` for are reported
+    /// where they are in `input`.
+    pub fn parse(input: &str, tile: impl FnMut(char) -> Option<T>) -> Result<Self, SolveError> {
+        let rows: Vec<Vec<T>> = parse_rows(input, tile)?;
+
+        // Report the first row that is not as long as the first one
+        if let Some((line, _)) = input
+            .lines()
+            .zip(rows.iter())
+            .find(|(_, row)| row.len() != rows[0].len())
+        {
+            return Err(SolveError::at(
+                input,
+                line,
+                format!("expected rows of {} tiles", rows[0].len()),
+            ));
+        }
+
+        Ok(Self::from_rows(rows).expect("rows have equal lengths"))
+    }
+
+    /// Like [`Grid::parse`], but rows may differ in length. Shorter rows are filled up to the
+    /// longest with `padding`, e.g. for maps whose right edge is ragged.
+    pub fn parse_padded(
+        input: &str,
+        padding: T,
+        tile: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, SolveError>
+    where
+        T: Clone,
+    {
+        let mut rows: Vec<Vec<T>> = parse_rows(input, tile)?;
+
+        let width: usize = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in rows.iter_mut() {
+            row.resize(width, padding.clone());
+        }
+
+        Ok(Self::from_rows(rows).expect("rows are padded to equal lengths"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `pos` is inside the grid.
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Cell at `pos`, `None` outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    /// Mutable cell at `pos`, `None` outside the grid.
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// Position one `step` of `(dx, dy)` away from `pos`, `None` if that leaves the grid.
+    pub fn step(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let next: Pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(next).then_some(next)
+    }
+
+    /// Positions of the orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS_4
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Positions of all surrounding neighbours of `pos` inside the grid, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Positions from `pos`, exclusive, in direction `dir` up to the edge of the grid.
+    pub fn ray(&self, pos: Pos, dir: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, dir), move |&pos| self.step(pos, dir))
+    }
+
+    /// Cells of row `y`.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width)
+    }
+
+    /// Cells of column `x` from top to bottom. Panics if `x` is outside of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(
+            x < self.width,
+            "column {} outside of {}x{} grid",
+            x,
+            self.width,
+            self.height
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width: usize = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Grid rotated by a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height: usize = self.height;
+        self.remap(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    /// Grid rotated by a quarter turn counter-clockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width: usize = self.width;
+        self.remap(self.height, self.width, |(x, y)| (width - 1 - y, x))
+    }
+
+    // Grid of the given size where each cell is taken from `source(pos)` of this grid
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells: Vec<T> = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[source((x, y))].clone());
+            }
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Grid drawn with one character per cell and one line per row, e.g. for debugging.
+    pub fn render(&self, mut tile: impl FnMut(&T) -> char) -> String {
+        let mut out: String = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            out.extend(row.iter().map(&mut tile));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} outside of {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {:?} outside of {}x{} grid", pos, width, height))
+    }
+}
+
+// Cells of each line of a character map, which must have at least one
+fn parse_rows<T>(
+    input: &str,
+    mut tile: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, SolveError> {
+    let rows: Vec<Vec<T>> = input
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(i, c)| {
+                    tile(c).ok_or_else(|| {
+                        SolveError::at(input, &line[i..], format!("invalid tile '{}'", c))
+                    })
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    if rows.first().is_none_or(Vec::is_empty) {
+        return Err(SolveError::new("expected a non-empty grid"));
+    }
+
+    Ok(rows)
+}
+
+/// Prints each cell with its own `Display`, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, SolveError> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<u32> = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let error = digits("123\n4x6").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: invalid tile 'x'");
+        let error = digits("123\n45").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected rows of 3 tiles"
+        );
+        assert!(digits("").is_err());
+
+        let grid: Grid<char> = Grid::parse_padded(" ab\nc", ' ', Some).unwrap();
+        assert_eq!(grid.render(|&c| c), " ab\nc  \n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid: Grid<u32> = digits("123\n456\n789").unwrap();
+        let cells =
+            |positions: Vec<Pos>| -> Vec<u32> { positions.iter().map(|&p| grid[p]).collect() };
+
+        assert_eq!(cells(grid.neighbours4((0, 0)).collect()), vec![2, 4]);
+        assert_eq!(cells(grid.neighbours4((1, 1)).collect()), vec![2, 6, 8, 4]);
+        assert_eq!(cells(grid.neighbours8((0, 0)).collect()), vec![2, 5, 4]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(cells(grid.ray((0, 1), (1, 0)).collect()), vec![5, 6]);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid: Grid<u32> = digits("123\n456").unwrap();
+
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<u32>>(), vec![2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.position(|&d| d > 4), Some((1, 1)));
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_right().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_left().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.map(|d| d * 2)[(0, 1)], 8);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(Grid::<u8>::from_rows(vec![]), None);
+        assert_eq!(Grid::<u8>::from_rows(vec![vec![], vec![]]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "0x3 grid has no cells")]
+    fn test_new_empty() {
+        Grid::new(0, 3, 0_u8);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 2 outside of 2x1 grid")]
+    fn test_column_outside() {
+        Grid::new(2, 1, 0_u8).column(2).count();
+    }
+}
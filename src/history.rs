@@ -0,0 +1,229 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fs, io,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{config::default_year, part_label, PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Timing of one part within a saved run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// Elapsed time of the part, or its median when benchmarked.
+    pub elapsed_ns: u64,
+    pub runs: u32,
+}
+
+impl Record {
+    pub fn new(result: &PartResult, runs: u32) -> Self {
+        Record {
+            day: result.day,
+            part: result.part,
+            elapsed_ns: result.elapsed.as_nanos() as u64,
+            runs,
+        }
+    }
+}
+
+/// A named set of timings, e.g. "baseline".
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Run {
+    pub name: String,
+    /// Runs saved before years were tracked belong to [`DEFAULT_YEAR`](crate::config::DEFAULT_YEAR).
+    #[serde(default = "default_year")]
+    pub year: u16,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub records: Vec<Record>,
+}
+
+impl Run {
+    pub fn new(name: &str, year: u16, records: Vec<Record>) -> Self {
+        Run {
+            name: name.to_string(),
+            year,
+            timestamp: now(),
+            records,
+        }
+    }
+}
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Every saved run, oldest first.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    /// Loads the history at `path`. A missing file is an empty history.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, contents + "\n")
+    }
+
+    pub fn push(&mut self, run: Run) {
+        self.runs.push(run);
+    }
+
+    /// The most recently saved run of `year` with the given name.
+    pub fn latest(&self, name: &str, year: u16) -> Option<&Run> {
+        self.runs
+            .iter()
+            .rev()
+            .find(|run| run.name == name && run.year == year)
+    }
+}
+
+/// Current timing of a part next to its baseline timing, if there is one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Option<Duration>,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change against the baseline in percent; positive means slower.
+    pub fn change(&self) -> Option<f64> {
+        self.baseline.filter(|b| !b.is_zero()).map(|baseline| {
+            let baseline = baseline.as_nanos() as f64;
+            (self.current.as_nanos() as f64 - baseline) / baseline * 100_f64
+        })
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+pub fn compare(baseline: &Run, current: &[Record]) -> Vec<Comparison> {
+    current
+        .iter()
+        .map(|record| Comparison {
+            day: record.day,
+            part: record.part,
+            baseline: baseline
+                .records
+                .iter()
+                .find(|r| r.day == record.day && r.part == record.part)
+                .map(|r| Duration::from_nanos(r.elapsed_ns)),
+            current: Duration::from_nanos(record.elapsed_ns),
+        })
+        .collect()
+}
+
+/// Prints one line per part and returns whether any part regressed by more than `threshold` percent.
+pub fn print_report(name: &str, comparisons: &[Comparison], threshold: f64) -> bool {
+    println!(
+        "{}Compared against \"{}\" (threshold: {}%){}",
+        ANSI_BOLD, name, threshold, ANSI_RESET
+    );
+
+    for c in comparisons {
+        let change = match (c.baseline, c.change()) {
+            (Some(baseline), Some(change)) => {
+                format!("{:.2?} -> {:.2?} ({:+.1}%)", baseline, c.current, change)
+            }
+            (Some(baseline), None) => format!("{:.2?} -> {:.2?}", baseline, c.current),
+            (None, _) => format!("{:.2?} {}(new){}", c.current, ANSI_ITALIC, ANSI_RESET),
+        };
+        let flag = if c.is_regression(threshold) {
+            format!(" {}REGRESSION{}", ANSI_BOLD, ANSI_RESET)
+        } else {
+            String::new()
+        };
+
+        println!(
+            "Day {:02} {}: {}{}",
+            c.day,
+            part_label(c.part),
+            change,
+            flag
+        );
+    }
+
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.is_regression(threshold))
+        .count();
+    if regressions > 0 {
+        println!("{} part(s) regressed.", regressions);
+    } else {
+        println!("No regressions.");
+    }
+
+    regressions > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, part: u8, elapsed_ns: u64) -> Record {
+        Record {
+            day,
+            part,
+            elapsed_ns,
+            runs: 1,
+        }
+    }
+
+    #[test]
+    fn test_latest() {
+        let mut history = History::default();
+        history.push(Run::new("baseline", 2022, vec![record(1, 1, 100)]));
+        history.push(Run::new("other", 2022, vec![record(1, 1, 200)]));
+        history.push(Run::new("baseline", 2022, vec![record(1, 1, 300)]));
+        history.push(Run::new("baseline", 2021, vec![record(1, 1, 400)]));
+
+        assert_eq!(
+            history.latest("baseline", 2022).unwrap().records,
+            vec![record(1, 1, 300)]
+        );
+        assert!(history.latest("missing", 2022).is_none());
+        assert!(history.latest("other", 2021).is_none());
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Run::new("baseline", 2022, vec![record(1, 1, 100), record(1, 2, 100)]);
+        let comparisons = compare(
+            &baseline,
+            &[record(1, 1, 105), record(1, 2, 150), record(2, 1, 10)],
+        );
+
+        assert!((comparisons[0].change().unwrap() - 5_f64).abs() < 1.0e-6);
+        assert!(!comparisons[0].is_regression(10_f64));
+        assert!((comparisons[1].change().unwrap() - 50_f64).abs() < 1.0e-6);
+        assert!(comparisons[1].is_regression(10_f64));
+        assert_eq!(comparisons[2].baseline, None);
+        assert!(!comparisons[2].is_regression(10_f64));
+    }
+}
@@ -5,230 +5,143 @@
  */
 use std::env;
 use std::fs;
-
+use std::io;
+
+pub mod answer;
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod error;
+pub mod graph;
 pub mod helpers;
+pub mod history;
+pub mod markdown;
+pub mod output;
+pub mod parser;
+pub mod readme;
+pub mod solution;
+pub mod submissions;
+pub mod verify;
+
+pub use answer::Answer;
+use bench::BenchResult;
+pub use error::{DayError, SolveError};
+pub use solution::{part_label, Day, PartResult, PuzzleParams, Solution};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
+// Multi-line answers (e.g. rendered letters) get their timing on a separate line.
+fn answer_separator(answer: &Answer) -> &'static str {
+    match answer {
+        Answer::Text(text) if text.contains('\n') => "\n",
+        _ => " ",
+    }
+}
 
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
+pub fn print_result(result: &PartResult) {
+    println!(
+        "🎄 {}{}{} 🎄",
+        ANSI_BOLD,
+        part_label(result.part),
+        ANSI_RESET
+    );
+    match &result.answer {
+        Some(answer) => {
+            println!(
+                "{}{}{}(elapsed: {:.2?}){}",
+                answer,
+                answer_separator(answer),
+                ANSI_ITALIC,
+                result.elapsed,
+                ANSI_RESET
+            );
         }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+        None if result.is_parse() => {
+            println!(
+                "{}(elapsed: {:.2?}){}",
+                ANSI_ITALIC, result.elapsed, ANSI_RESET
+            );
+        }
+        None => {
+            println!("not solved.")
+        }
+    }
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
-
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn print_bench_result(result: &BenchResult) {
+    println!(
+        "🎄 {}{}{} 🎄",
+        ANSI_BOLD,
+        part_label(result.part),
+        ANSI_RESET
+    );
+    match &result.answer {
+        Some(answer) => {
+            println!(
+                "{}{}{}(median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, runs: {}){}",
+                answer,
+                answer_separator(answer),
+                ANSI_ITALIC,
+                result.median,
+                result.mean,
+                result.stddev,
+                result.min,
+                result.runs,
+                ANSI_RESET
+            );
+        }
+        None if result.part == solution::PARSE => {
+            println!(
+                "{}(median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, runs: {}){}",
+                ANSI_ITALIC,
+                result.median,
+                result.mean,
+                result.stddev,
+                result.min,
+                result.runs,
+                ANSI_RESET
+            );
+        }
+        None => {
+            println!("not solved.")
+        }
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    try_read_file(folder, year, day).expect("could not open input file")
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+/// Reads `NN.txt` from the year's subfolder of the configured `inputs` or `examples` folder,
+/// e.g. `src/inputs/2022/01.txt`.
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> io::Result<String> {
+    read_src_file(folder, year, &format!("{:02}.txt", day))
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Reads the example input of a day: `<examples>/<year>/NN.txt` if `name` is empty,
+/// otherwise the named variant `<examples>/<year>/NN-<name>.txt`.
+pub fn read_example(year: u16, day: u8, name: &str) -> String {
+    try_read_example(year, day, name).expect("could not open example file")
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+pub fn try_read_example(year: u16, day: u8, name: &str) -> io::Result<String> {
+    match name {
+        "" => try_read_file("examples", year, day),
+        name => read_src_file("examples", year, &format!("{:02}-{}.txt", day, name)),
     }
 }
 
-pub mod aoc_cli {
-    use std::{
-        fmt::Display,
-        fs::create_dir_all,
-        process::{Command, Output, Stdio},
-    };
-
-    pub enum AocCliError {
-        CommandNotFound,
-        CommandNotCallable,
-        BadExitStatus(Output),
-        IoError,
-    }
-
-    impl Display for AocCliError {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                AocCliError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-                AocCliError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-                AocCliError::BadExitStatus(_) => {
-                    write!(f, "aoc-cli exited with a non-zero status.")
-                }
-                AocCliError::IoError => write!(f, "could not write output files to file system."),
-            }
-        }
-    }
-
-    pub fn check() -> Result<(), AocCliError> {
-        Command::new("aoc")
-            .arg("-V")
-            .output()
-            .map_err(|_| AocCliError::CommandNotFound)?;
-        Ok(())
-    }
-
-    pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
-        let args = build_args("read", &[], day, year);
-        call_aoc_cli(&args)
-    }
-
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
-
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
+fn read_src_file(folder: &str, year: u16, file_name: &str) -> io::Result<String> {
+    let cwd = env::current_dir()?;
 
-        let args = build_args(
-            "download",
-            &[
-                "--overwrite".into(),
-                "--input-file".into(),
-                input_path.to_string(),
-                "--puzzle-file".into(),
-                puzzle_path.to_string(),
-            ],
-            day,
-            year,
-        );
-
-        let output = call_aoc_cli(&args)?;
-
-        if output.status.success() {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-            Ok(output)
-        } else {
-            Err(AocCliError::BadExitStatus(output))
-        }
-    }
-
-    fn get_input_path(day: u8) -> String {
-        let day_padded = format!("{:02}", day);
-        format!("src/inputs/{}.txt", day_padded)
-    }
+    let filepath = cwd
+        .join(config::config().folder(folder))
+        .join(year.to_string())
+        .join(file_name);
 
-    fn get_puzzle_path(day: u8) -> String {
-        let day_padded = format!("{:02}", day);
-        format!("src/puzzles/{}.md", day_padded)
-    }
-
-    fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
-        let mut cmd_args = args.to_vec();
-
-        if let Some(year) = year {
-            cmd_args.push("--year".into());
-            cmd_args.push(year.to_string());
-        }
-
-        cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
-
-        cmd_args
-    }
-
-    fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
-        if cfg!(debug_assertions) {
-            println!("Calling >aoc with: {}", args.join(" "));
-        }
-
-        Command::new("aoc")
-            .args(args)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .output()
-            .map_err(|_| AocCliError::CommandNotCallable)
-    }
+    fs::read_to_string(filepath)
 }
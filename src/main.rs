@@ -2,47 +2,391 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::{
+    cli::{self, DaySelection, RunOptions},
+    config::config,
+    days,
+    history::{self, History, Record, Run},
+    output::{self, Format},
+    readme,
+    solution::Measurement,
+    Day, PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use std::{
+    collections::BTreeMap,
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+struct Args {
+    options: RunOptions,
+    year: u16,
+    save: Option<String>,
+    compare: Option<String>,
+    threshold: f64,
+    readme: bool,
+    jobs: usize,
+    days: DaySelection,
+    parts: Vec<u8>,
+    only_solved: bool,
+}
+
+/// Everything measured for one day, collected on a worker thread.
+struct DayRun {
+    day: u8,
+    /// The parse phase followed by the parts, or why the day could not be run.
+    parts: Result<Vec<Measurement>, DayFailure>,
+}
+
+/// Why a day could not be run.
+enum DayFailure {
+    /// The day has no input file yet.
+    MissingInput,
+    /// Parsing or a part failed.
+    Failed {
+        reason: String,
+        /// The input line that made the day fail, with a caret under the failing column.
+        snippet: Option<String>,
+    },
+}
+
+impl DayFailure {
+    fn reason(&self) -> &str {
+        match self {
+            DayFailure::MissingInput => "missing input file",
+            DayFailure::Failed { reason, .. } => reason,
+        }
+    }
+
+    fn snippet(&self) -> Option<&str> {
+        match self {
+            DayFailure::MissingInput => None,
+            DayFailure::Failed { snippet, .. } => snippet.as_deref(),
+        }
+    }
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        options: cli::parse(&mut args)?,
+        year: cli::parse_year(&mut args)?,
+        save: args.opt_value_from_str("--save")?,
+        compare: args.opt_value_from_str("--compare")?,
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+        readme: args.contains("--readme"),
+        jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+        parts: args
+            .opt_value_from_fn("--part", cli::parse_part)?
+            .map_or(vec![1, 2], |part| vec![part]),
+        only_solved: args.contains("--only-solved"),
+        days: args.opt_free_from_str()?.unwrap_or_default(),
+    };
+    cli::finish(args)?;
+    Ok(parsed)
+}
+
+fn run_day(day: &Day, parts: &[u8], options: &RunOptions) -> DayRun {
+    let parts = match advent_of_code::try_read_file("inputs", day.year, day.day) {
+        Ok(input) => day
+            .run(&input, parts, options)
+            .map_err(|e| DayFailure::Failed {
+                reason: e.to_string(),
+                snippet: e.error.snippet(),
+            }),
+        Err(_) => Err(DayFailure::MissingInput),
+    };
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
+    DayRun {
+        day: day.day,
+        parts,
+    }
+}
+
+/// For `--only-solved`: drops the parts without an answer and tells whether anything of the day
+/// is left to report. Days without an input file or a solved part are left out, but days that
+/// failed are always reported.
+fn keep_solved(run: &mut DayRun) -> bool {
+    match &mut run.parts {
+        Ok(parts) => {
+            parts.retain(|measurement| {
+                let result = measurement.to_part_result();
+                result.is_parse() || result.is_solved()
+            });
+            // Only the parse phase is left if no part is solved.
+            parts.len() > 1
+        }
+        Err(DayFailure::MissingInput) => false,
+        Err(DayFailure::Failed { .. }) => true,
+    }
+}
+
+/// Solves the given days on `jobs` worker threads and hands them to `report` in day order,
+/// each as soon as it and all days before it are done.
+/// Every day is timed on the thread that runs it, while other days run at the same time, so with
+/// more than one job the timings are skewed by the days around them and are not recorded.
+fn run_days(
+    days: &[Day],
+    parts: &[u8],
+    options: &RunOptions,
+    jobs: usize,
+    mut report: impl FnMut(DayRun),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1).min(days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    if sender.send(run_day(day, parts, options)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending: BTreeMap<u8, DayRun> = BTreeMap::new();
+        let mut expected = days.iter().map(|day| day.day);
+        let mut waiting_for = expected.next();
+        for run in receiver {
+            pending.insert(run.day, run);
+            while let Some(run) = waiting_for.and_then(|day| pending.remove(&day)) {
+                report(run);
+                waiting_for = expected.next();
             }
+        }
+    });
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    // Days running side by side skew each other's timings, so they can't be benchmarked, recorded
+    // or compared.
+    if args.jobs > 1
+        && (args.options.bench.is_some()
+            || args.save.is_some()
+            || args.compare.is_some()
+            || args.readme)
+    {
+        eprintln!("--jobs can't be combined with --bench, --save, --compare or --readme, which need isolated timings.");
+        process::exit(1);
+    }
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+    // Timings from debug builds are not representative, so they can be kept out of the records.
+    let records_timings = args.readme || args.save.is_some();
+    if records_timings && cfg!(debug_assertions) && config().require_release {
+        eprintln!("Refusing to record timings from a debug build. Run with `--release`.");
+        process::exit(1);
+    }
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    let is_text = args.options.format == Format::Text;
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    if days::of_year(args.year).is_empty() {
+        eprintln!("No days are registered for {}.", args.year);
+        process::exit(1);
+    }
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+    let selected: Vec<Day> = days::of_year(args.year)
+        .iter()
+        .filter(|day| args.days.contains(day.day))
+        .copied()
+        .collect();
+
+    let mut results: Vec<PartResult> = vec![];
+    let mut failed = false;
+    run_days(
+        &selected,
+        &args.parts,
+        &args.options,
+        args.jobs,
+        |mut run| {
+            if args.only_solved && !keep_solved(&mut run) {
+                return;
+            }
+
+            if is_text {
+                println!("----------");
+                println!("{}| Day {:02} |{}", ANSI_BOLD, run.day, ANSI_RESET);
+                println!("----------");
+            }
+
+            match run.parts {
+                Ok(parts) => {
+                    for measurement in parts {
+                        measurement.emit(&args.options);
+                        let result = measurement.to_part_result();
+                        if result.is_parse() || result.is_solved() {
+                            results.push(result);
+                        }
+                    }
+                }
+                Err(failure) => {
+                    if is_text {
+                        println!("Not solved ({}).", failure.reason());
+                        if let Some(snippet) = failure.snippet() {
+                            println!("{}", snippet);
+                        }
+                    } else {
+                        eprintln!("Failed to solve day {}: {}", run.day, failure.reason());
+                        if let Some(snippet) = failure.snippet() {
+                            eprintln!("{}", snippet);
+                        }
+                        output::emit_error(run.day, failure.reason(), &args.options);
+                    }
+                    // Days that are not downloaded yet are expected, failing solutions are not.
+                    if let DayFailure::Failed { .. } = failure {
+                        failed = true;
+                    }
                 }
-            );
+            }
+        },
+    );
+
+    if is_text {
+        let total: Duration = results.iter().map(|result| result.elapsed).sum();
+        let label = if args.options.bench.is_some() {
+            "Total (median):"
+        } else {
+            "Total:"
+        };
+        println!(
+            "{}{}{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            label,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+    }
+
+    if args.readme {
+        if cfg!(debug_assertions) {
+            eprintln!("Warning: updating the readme with timings from a debug build.");
+        }
+        let readme_path = &config().readme;
+        if let Err(e) = readme::update(readme_path, args.year, &results) {
+            eprintln!("Failed to update \"{}\": {}", readme_path.display(), e);
+            process::exit(1);
+        }
+        println!("---");
+        println!("🎄 Updated benchmarks in \"{}\".", readme_path.display());
+    }
+
+    if args.save.is_none() && args.compare.is_none() {
+        if failed {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let runs = args.options.bench.unwrap_or(1).max(1);
+    let records: Vec<Record> = results
+        .iter()
+        .map(|result| Record::new(result, runs))
+        .collect();
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let history_path = &config().history;
+    let mut history = match History::load(history_path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", history_path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    let mut regressed = false;
+    if let Some(name) = &args.compare {
+        match history.latest(name, args.year) {
+            Some(baseline) => {
+                println!("---");
+                let comparisons = history::compare(baseline, &records);
+                regressed = history::print_report(name, &comparisons, args.threshold);
+            }
+            None => {
+                eprintln!(
+                    "No saved run named \"{}\" for {} in \"{}\".",
+                    name,
+                    args.year,
+                    history_path.display()
+                );
+                process::exit(1);
             }
+        }
+    }
+
+    if let Some(name) = &args.save {
+        history.push(Run::new(name, args.year, records));
+        if let Err(e) = history.save(history_path) {
+            eprintln!("Failed to write \"{}\": {}", history_path.display(), e);
+            process::exit(1);
+        }
+        println!("---");
+        println!(
+            "🎄 Saved run \"{}\" to \"{}\".",
+            name,
+            history_path.display()
+        );
+    }
+
+    if regressed || failed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn measurement(part: u8, answer: Option<u32>) -> Measurement {
+        Measurement::Once(PartResult {
+            day: 1,
+            part,
+            answer: answer.map(Into::into),
+            elapsed: Duration::from_nanos(100),
         })
-        .sum();
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    fn run(parts: Result<Vec<Measurement>, DayFailure>) -> DayRun {
+        DayRun { day: 1, parts }
+    }
+
+    #[test]
+    fn test_keep_solved() {
+        let mut solved = run(Ok(vec![
+            measurement(0, None),
+            measurement(1, Some(24000)),
+            measurement(2, None),
+        ]));
+        assert!(keep_solved(&mut solved));
+        assert_eq!(solved.parts.map(|parts| parts.len()).ok(), Some(2));
+
+        let mut unsolved = run(Ok(vec![measurement(0, None), measurement(1, None)]));
+        assert!(!keep_solved(&mut unsolved));
+        assert!(!keep_solved(&mut run(Err(DayFailure::MissingInput))));
+
+        // Failures are reported even if nothing is solved
+        let mut failed = run(Err(DayFailure::Failed {
+            reason: String::from("Parse failed: line 1, column 1: expected a number"),
+            snippet: None,
+        }));
+        assert!(keep_solved(&mut failed));
+    }
 }
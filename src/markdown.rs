@@ -0,0 +1,290 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Renders puzzle markdown for the terminal: headings and emphasis in bold, inline code in
+/// italics and code blocks indented. Without `styled`, the markup is only stripped.
+pub fn render(markdown: &str, styled: bool) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut rendered = String::new();
+    let mut in_code_block = false;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            rendered.push_str(&format!("    {}\n", line));
+            continue;
+        }
+
+        let is_underlined = lines.get(i).is_some_and(|next| is_underline(next));
+        if is_underlined || line.starts_with('#') {
+            if is_underlined {
+                i += 1;
+            }
+            let heading = render_inline(line.trim_start_matches('#').trim_start(), false);
+            rendered.push_str(&style(&heading, ANSI_BOLD, styled));
+        } else if let Some(item) = line.strip_prefix("* ").or(line.strip_prefix("- ")) {
+            rendered.push_str("  • ");
+            rendered.push_str(&render_inline(item, styled));
+        } else {
+            rendered.push_str(&render_inline(line, styled));
+        }
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+fn is_underline(line: &str) -> bool {
+    line.len() >= 3 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+fn style(text: &str, ansi: &str, styled: bool) -> String {
+    if styled {
+        format!("{}{}{}", ansi, text, ANSI_RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+// Returns the positions of the `]` and `)` closing the link that opens at `start`, if it is one.
+fn link_end(chars: &[char], start: usize) -> Option<(usize, usize)> {
+    let mut depth = 0;
+    let mut i = start;
+    let text_end = loop {
+        match chars.get(i)? {
+            '\\' => i += 1,
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    break i;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    };
+
+    if chars.get(text_end + 1) != Some(&'(') {
+        return None;
+    }
+
+    let mut depth = 0;
+    for (offset, c) in chars[text_end + 1..].iter().enumerate() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((text_end, text_end + 1 + offset));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn render_inline(text: &str, styled: bool) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut rendered = String::new();
+    let (mut bold, mut italic) = (false, false);
+    let mut links: Vec<(usize, usize)> = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if i + 1 < chars.len() => {
+                rendered.push(chars[i + 1]);
+                i += 1;
+            }
+            '*' | '`' => {
+                if c == '*' {
+                    bold = !bold;
+                } else {
+                    italic = !italic;
+                }
+                if styled {
+                    rendered.push_str(ANSI_RESET);
+                    if bold {
+                        rendered.push_str(ANSI_BOLD);
+                    }
+                    if italic {
+                        rendered.push_str(ANSI_ITALIC);
+                    }
+                }
+            }
+            '[' => match link_end(&chars, i) {
+                Some(end) => links.push(end),
+                None => rendered.push(c),
+            },
+            ']' if links.last().is_some_and(|&(text_end, _)| text_end == i) => {
+                let (_, url_end) = links.pop().unwrap_or_default();
+                i = url_end;
+            }
+            _ => rendered.push(c),
+        }
+        i += 1;
+    }
+
+    if styled && (bold || italic) {
+        rendered.push_str(ANSI_RESET);
+    }
+    rendered
+}
+
+/// Every fenced code block with the text written since the block before it.
+fn code_blocks(markdown: &str) -> Vec<(String, String)> {
+    let mut blocks: Vec<(String, String)> = vec![];
+    let mut text = String::new();
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        match (&mut block, line.starts_with("```")) {
+            (None, true) => block = Some(String::new()),
+            (Some(_), true) => {
+                blocks.push((std::mem::take(&mut text), block.take().unwrap_or_default()));
+            }
+            (Some(block), false) => {
+                block.push_str(line);
+                block.push('\n');
+            }
+            (None, false) => {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+    }
+
+    blocks
+}
+
+/// The example input of a puzzle: the code block at `index` if given, otherwise the first block
+/// introduced by "For example", falling back to the first block.
+pub fn example(markdown: &str, index: Option<usize>) -> Option<String> {
+    let blocks = code_blocks(markdown);
+
+    let block = match index {
+        Some(index) => blocks.get(index),
+        None => blocks
+            .iter()
+            .find(|(text, _)| text.to_lowercase().contains("for example"))
+            .or(blocks.first()),
+    };
+
+    block.map(|(_, block)| block.trim_end().to_string() + "\n")
+}
+
+// Contents of code spans that are emphasized as a whole, i.e. `*42*` or *`42`*.
+fn emphasized_code(line: &str) -> Vec<String> {
+    let mut found: Vec<String> = vec![];
+    let mut rest = line;
+
+    while let Some((start, open, close)) = [("`*", "*`"), ("*`", "`*")]
+        .iter()
+        .filter_map(|&(open, close)| rest.find(open).map(|start| (start, open, close)))
+        .min()
+    {
+        let inner = &rest[start + open.len()..];
+        match inner.find(close) {
+            Some(end) => {
+                found.push(inner[..end].to_string());
+                rest = &inner[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+
+    found
+}
+
+/// Guesses the expected example answers of both parts: the last emphasized code span in the
+/// description of each part, e.g. "this is *`24000`*".
+pub fn example_answers(markdown: &str) -> (Option<String>, Option<String>) {
+    let mut answers: [Option<String>; 2] = [None, None];
+    let mut part = 0;
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if line.contains("--- Part Two ---") {
+            part = 1;
+        } else if !in_code_block && !line.starts_with("Your puzzle answer was") {
+            if let Some(answer) = emphasized_code(line).pop() {
+                answers[part] = Some(answer);
+            }
+        }
+    }
+
+    let [one, two] = answers;
+    (one, two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let markdown = "\\--- Day 1: Test ---\n----------\n\n\
+            Find the *sum* of `*24000*`, see [here](/2022/about) and [a [b](x_(y))] \\* 2.\n\n\
+            ```\n1000\n*2000*\n```\n\n\
+            * One `1`\n";
+
+        assert_eq!(
+            render(markdown, false),
+            "--- Day 1: Test ---\n\n\
+             Find the sum of 24000, see here and [a b] * 2.\n\n\
+             \x20   1000\n    *2000*\n\n\
+             \x20 • One 1\n"
+        );
+        assert_eq!(
+            render("## Title\nsome *sum*", true),
+            format!(
+                "{b}Title{r}\nsome {r}{b}sum{r}\n",
+                b = ANSI_BOLD,
+                r = ANSI_RESET
+            )
+        );
+    }
+
+    const PUZZLE: &str = "\\--- Day 1: Test ---\n----------\n\n\
+        The list looks like this:\n\n```\na\n```\n\n\
+        For example:\n\n```\n1000\n2000\n\n3000\n\n```\n\n\
+        * The first Elf carries `*3000*` Calories.\n\n\
+        In the example above, this is *`3000`*, times `2` is `*6000*`.\n\n\
+        Your puzzle answer was `71780`.\n\n\
+        \\--- Part Two ---\n----------\n\n\
+        ```\n`*1*`\n```\n\n\
+        The sum is `*45000*`.\n";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(example(PUZZLE, None), Some("1000\n2000\n\n3000\n".into()));
+        assert_eq!(example(PUZZLE, Some(0)), Some("a\n".into()));
+        assert_eq!(example(PUZZLE, Some(3)), None);
+        assert_eq!(example("```\nb\n```\n", None), Some("b\n".into()));
+        assert_eq!(example("no blocks", None), None);
+    }
+
+    #[test]
+    fn test_example_answers() {
+        assert_eq!(
+            example_answers(PUZZLE),
+            (Some("6000".into()), Some("45000".into()))
+        );
+        assert_eq!(example_answers("no answers"), (None, None));
+    }
+}
@@ -0,0 +1,257 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fmt::Display, str::FromStr, sync::Once};
+
+use serde::Serialize;
+
+use crate::{
+    bench::BenchResult, cli::RunOptions, print_bench_result, print_result, Answer, PartResult,
+};
+
+/// How results are written to stdout, selected with `--format text|json|csv`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable output (default).
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+    /// Comma-separated values with a header row.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format \"{}\", expected text|json|csv", s)),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+static CSV_HEADER: Once = Once::new();
+
+#[derive(Serialize)]
+struct Row<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a Answer>,
+    elapsed_ns: u128,
+}
+
+/// A day that failed, e.g. because its input is missing or does not parse.
+#[derive(Serialize)]
+struct ErrorRow<'a> {
+    day: u8,
+    error: &'a str,
+}
+
+impl<'a> From<&'a PartResult> for Row<'a> {
+    fn from(result: &'a PartResult) -> Self {
+        Row {
+            day: result.day,
+            part: result.part,
+            answer: result.answer.as_ref(),
+            elapsed_ns: result.elapsed.as_nanos(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct BenchRow<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a Answer>,
+    runs: u32,
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+    stddev_ns: u128,
+}
+
+impl<'a> From<&'a BenchResult> for BenchRow<'a> {
+    fn from(result: &'a BenchResult) -> Self {
+        BenchRow {
+            day: result.day,
+            part: result.part,
+            answer: result.answer.as_ref(),
+            runs: result.runs,
+            min_ns: result.min.as_nanos(),
+            median_ns: result.median.as_nanos(),
+            mean_ns: result.mean.as_nanos(),
+            stddev_ns: result.stddev.as_nanos(),
+        }
+    }
+}
+
+/// Writes a single result in the format of `options`.
+pub fn emit(result: &PartResult, options: &RunOptions) {
+    match options.format {
+        Format::Text => print_result(result),
+        Format::Json => println!("{}", to_json(&Row::from(result))),
+        Format::Csv => {
+            print_csv_header(options);
+            println!("{}", to_csv(result));
+        }
+    }
+}
+
+/// Writes a single benchmark in the format of `options`.
+pub fn emit_bench(result: &BenchResult, options: &RunOptions) {
+    match options.format {
+        Format::Text => print_bench_result(result),
+        Format::Json => println!("{}", to_json(&BenchRow::from(result))),
+        Format::Csv => {
+            print_csv_header(options);
+            println!("{}", to_bench_csv(result));
+        }
+    }
+}
+
+/// Writes a day that failed in the format of `options`. The text format reports failures itself.
+pub fn emit_error(day: u8, error: &str, options: &RunOptions) {
+    match options.format {
+        Format::Text => {}
+        Format::Json => println!("{}", to_json(&ErrorRow { day, error })),
+        Format::Csv => {
+            print_csv_header(options);
+            println!("{}", to_error_csv(day, error, options.bench.is_some()));
+        }
+    }
+}
+
+// Failed days get a row with only the day and the error, so every row has the error column.
+fn print_csv_header(options: &RunOptions) {
+    CSV_HEADER.call_once(|| {
+        if options.bench.is_some() {
+            println!("day,part,answer,runs,min_ns,median_ns,mean_ns,stddev_ns,error");
+        } else {
+            println!("day,part,answer,elapsed_ns,error");
+        }
+    });
+}
+
+fn to_json(row: &impl Serialize) -> String {
+    serde_json::to_string(row).expect("result rows are always serializable")
+}
+
+fn csv_answer(answer: &Option<Answer>) -> String {
+    match answer {
+        Some(Answer::Text(text)) => csv_text(text),
+        Some(answer) => answer.to_string(),
+        None => String::new(),
+    }
+}
+
+// Quotes text that would otherwise break the row, e.g. multi-line output.
+fn csv_text(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        String::from(text)
+    }
+}
+
+fn to_csv(result: &PartResult) -> String {
+    format!(
+        "{},{},{},{},",
+        result.day,
+        result.part,
+        csv_answer(&result.answer),
+        result.elapsed.as_nanos()
+    )
+}
+
+fn to_bench_csv(result: &BenchResult) -> String {
+    format!(
+        "{},{},{},{},{},{},{},{},",
+        result.day,
+        result.part,
+        csv_answer(&result.answer),
+        result.runs,
+        result.min.as_nanos(),
+        result.median.as_nanos(),
+        result.mean.as_nanos(),
+        result.stddev.as_nanos()
+    )
+}
+
+fn to_error_csv(day: u8, error: &str, bench: bool) -> String {
+    // Empty part and answer, then one empty column per run count and timing
+    let empty: usize = if bench { 7 } else { 3 };
+    format!("{}{},{}", day, ",".repeat(empty), csv_text(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(answer: Option<Answer>) -> PartResult {
+        PartResult {
+            day: 5,
+            part: 2,
+            answer,
+            elapsed: Duration::from_nanos(1500),
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&Row::from(&result(Some("MCD".into())))),
+            r#"{"day":5,"part":2,"answer":"MCD","elapsed_ns":1500}"#
+        );
+        assert_eq!(
+            to_json(&Row::from(&result(Some(45000.into())))),
+            r#"{"day":5,"part":2,"answer":45000,"elapsed_ns":1500}"#
+        );
+        assert_eq!(
+            to_json(&Row::from(&result(None))),
+            r#"{"day":5,"part":2,"answer":null,"elapsed_ns":1500}"#
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(to_csv(&result(Some("MCD".into()))), "5,2,MCD,1500,");
+        assert_eq!(to_csv(&result(Some(45000.into()))), "5,2,45000,1500,");
+        assert_eq!(to_csv(&result(None)), "5,2,,1500,");
+        assert_eq!(
+            to_csv(&result(Some("a,\"b\"".into()))),
+            "5,2,\"a,\"\"b\"\"\",1500,"
+        );
+    }
+
+    #[test]
+    fn test_error_rows() {
+        assert_eq!(
+            to_json(&ErrorRow {
+                day: 5,
+                error: "missing input file"
+            }),
+            r#"{"day":5,"error":"missing input file"}"#
+        );
+        assert_eq!(
+            to_error_csv(5, "line 1, column 2: bad", false),
+            "5,,,,\"line 1, column 2: bad\""
+        );
+        assert_eq!(to_error_csv(5, "bad", true), "5,,,,,,,,bad");
+    }
+}
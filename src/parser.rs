@@ -0,0 +1,196 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use nom::{
+    character::complete::newline,
+    combinator::cut,
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    Err, IResult, Parser,
+};
+
+use crate::SolveError;
+
+/// Result of a nom parser in a solution. [`VerboseError`] keeps what was expected where and wraps
+/// it in the labels given with [`nom::error::context`], which [`parse_all`] reports.
+pub type ParseResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+
+/// Runs `parser` over the whole `input`. Only whitespace, e.g. the final newline, may be left over.
+/// A failure is reported at its line and column with what was expected there.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> Result<O, SolveError> {
+    parse_section(input, input, parser)
+}
+
+/// Like [`parse_all`] for a `section` of `input`, e.g. the part after an empty line. Failures are
+/// still located in the whole input.
+pub fn parse_section<'a, O>(
+    input: &'a str,
+    section: &'a str,
+    mut parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> Result<O, SolveError> {
+    match parser.parse(section) {
+        Ok((rest, output)) => {
+            let rest = rest.trim_start();
+            if rest.is_empty() {
+                Ok(output)
+            } else {
+                Err(SolveError::at(
+                    input,
+                    rest,
+                    format!("expected end of input, found {}", found(rest)),
+                ))
+            }
+        }
+        Err(Err::Error(error) | Err::Failure(error)) => Err(to_solve_error(input, error)),
+        Err(Err::Incomplete(_)) => Err(SolveError::at(
+            input,
+            &section[section.len()..],
+            "unexpected end of input",
+        )),
+    }
+}
+
+/// Like [`nom::multi::separated_list1`], but once a separator matches, the next item has to parse.
+/// A broken item is then reported where it breaks instead of ending the list early and leaving the
+/// rest over. Separators followed by nothing but whitespace end the list.
+pub fn separated<'a, O, O2, S, F>(
+    mut separator: S,
+    mut item: F,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<O>>
+where
+    S: Parser<&'a str, O2, VerboseError<&'a str>>,
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    move |input: &'a str| {
+        let (mut input, first) = item.parse(input)?;
+        let mut items: Vec<O> = vec![first];
+
+        loop {
+            let Ok((rest, _)) = separator.parse(input) else {
+                return Ok((input, items));
+            };
+            if rest.trim_start().is_empty() {
+                return Ok((input, items));
+            }
+
+            let (rest, next) = cut(|i| item.parse(i))(rest)?;
+            input = rest;
+            items.push(next);
+        }
+    }
+}
+
+/// One `item` per line, see [`separated`].
+pub fn lines<'a, O, F>(item: F) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<O>>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>,
+{
+    separated(newline, item)
+}
+
+// The first error is where parsing failed, the rest are the parsers around it from the inside out.
+fn to_solve_error(input: &str, error: VerboseError<&str>) -> SolveError {
+    let Some((at, kind)) = error.errors.first() else {
+        return SolveError::new("invalid input");
+    };
+    // Parsers that started where parsing failed
+    let mut around = error
+        .errors
+        .iter()
+        .filter(|(i, _)| i.as_ptr() == at.as_ptr())
+        .map(|(_, kind)| kind);
+
+    // A label given right there describes what was expected best. Without one, the error of
+    // an `alt` only names its last alternative, so it says nothing about what was expected.
+    let context: Option<&str> = around.clone().find_map(|kind| match kind {
+        VerboseErrorKind::Context(context) => Some(*context),
+        _ => None,
+    });
+    let in_alt: bool = around.any(|kind| matches!(kind, VerboseErrorKind::Nom(ErrorKind::Alt)));
+
+    let expected: Option<String> = match (context, kind) {
+        (Some(context), _) => Some(String::from(context)),
+        (None, _) if in_alt => None,
+        (None, VerboseErrorKind::Char(c)) => Some(describe(*c)),
+        (None, VerboseErrorKind::Nom(ErrorKind::Digit)) => Some(String::from("a number")),
+        (None, VerboseErrorKind::Nom(ErrorKind::Alpha)) => Some(String::from("a letter")),
+        (None, VerboseErrorKind::Nom(ErrorKind::AlphaNumeric)) => {
+            Some(String::from("a letter or digit"))
+        }
+        (None, VerboseErrorKind::Nom(ErrorKind::Space | ErrorKind::MultiSpace)) => {
+            Some(String::from("whitespace"))
+        }
+        (None, VerboseErrorKind::Nom(ErrorKind::Eof)) => Some(String::from("end of input")),
+        _ => None,
+    };
+
+    match expected {
+        Some(expected) => SolveError::at(
+            input,
+            at,
+            format!("expected {}, found {}", expected, found(at)),
+        ),
+        None => SolveError::at(input, at, format!("unexpected {}", found(at))),
+    }
+}
+
+// What is at the start of `rest`, for messages.
+fn found(rest: &str) -> String {
+    rest.chars()
+        .next()
+        .map_or(String::from("end of input"), describe)
+}
+
+fn describe(c: char) -> String {
+    match c {
+        '\n' => String::from("line break"),
+        c => format!("{:?}", c),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        branch::alt, bytes::complete::tag, character::complete, error::context,
+        sequence::separated_pair,
+    };
+
+    use super::*;
+
+    fn parse_pair(input: &str) -> ParseResult<'_, (u32, u32)> {
+        separated_pair(complete::u32, context("\",\"", tag(",")), complete::u32)(input)
+    }
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(
+            parse_all("1,2\n3,4\n", lines(parse_pair)),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+
+        let error = parse_all("1,2\n3,x\n", lines(parse_pair)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a number, found 'x'"
+        );
+        assert_eq!(error.snippet().unwrap(), "  |\n2 | 3,x\n  |   ^");
+
+        let error = parse_all("1,2\n3;4", lines(parse_pair)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected \",\", found ';'"
+        );
+
+        let error = parse_all("x", alt((parse_pair, parse_pair))).unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: unexpected 'x'");
+
+        let error = parse_all("1,2\n\n3,4", parse_pair).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected end of input, found '3'"
+        );
+    }
+}
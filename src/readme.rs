@@ -0,0 +1,127 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fs, io, path::Path, time::Duration};
+
+use crate::{solution::PARSE, PartResult};
+
+const MARKER_START: &str = "<!--- benchmarking table --->";
+const MARKER_END: &str = "<!--- benchmarking table end --->";
+
+/// Replaces the benchmarking table section of the readme at `path` with the given results.
+pub fn update(path: impl AsRef<Path>, year: u16, results: &[PartResult]) -> io::Result<()> {
+    let readme = fs::read_to_string(&path)?;
+    let updated = replace_section(&readme, &render_table(year, results)).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "benchmarking markers not found")
+    })?;
+    fs::write(path, updated)
+}
+
+fn replace_section(readme: &str, table: &str) -> Option<String> {
+    let start = readme.find(MARKER_START)? + MARKER_START.len();
+    let end = start + readme[start..].find(MARKER_END)?;
+
+    Some(format!(
+        "{}\n{}\n{}",
+        &readme[..start],
+        table,
+        &readme[end..]
+    ))
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("`{:.1?}`", duration)
+}
+
+fn render_table(year: u16, results: &[PartResult]) -> String {
+    let mut days: Vec<u8> = results.iter().map(|r| r.day).collect();
+    days.dedup();
+
+    let mut table = String::from(
+        "## Benchmarks\n\n| Day | Parse | Part 1 | Part 2 | Total |\n| :---: | :---: | :---: | :---: | :---: |\n",
+    );
+    let mut total = Duration::ZERO;
+
+    for day in days {
+        let part = |part: u8| {
+            results
+                .iter()
+                .find(|r| r.day == day && r.part == part && (r.is_parse() || r.is_solved()))
+                .map(|r| r.elapsed)
+        };
+        let (parse, one, two) = (part(PARSE), part(1), part(2));
+        let day_total =
+            parse.unwrap_or_default() + one.unwrap_or_default() + two.unwrap_or_default();
+        total += day_total;
+
+        table.push_str(&format!(
+            "| [Day {}](./src/days/y{}/day{:02}.rs) | {} | {} | {} | {} |\n",
+            day,
+            year,
+            day,
+            parse.map_or("-".into(), format_duration),
+            one.map_or("-".into(), format_duration),
+            two.map_or("-".into(), format_duration),
+            format_duration(day_total),
+        ));
+    }
+
+    table.push_str(&format!(
+        "\n**Total: {:.2}ms**\n",
+        total.as_secs_f64() * 1000_f64
+    ));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    fn result(day: u8, part: u8, micros: u64) -> PartResult {
+        PartResult {
+            day,
+            part,
+            answer: Some(Answer::from(0)),
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_render_table() {
+        let parse = PartResult {
+            answer: None,
+            ..result(1, PARSE, 5)
+        };
+        let table = render_table(
+            2022,
+            &[
+                parse,
+                result(1, 1, 10),
+                result(1, 2, 20),
+                result(2, 1, 1500),
+            ],
+        );
+        assert_eq!(
+            table,
+            "## Benchmarks\n\n| Day | Parse | Part 1 | Part 2 | Total |\n| :---: | :---: | :---: | :---: | :---: |\n\
+             | [Day 1](./src/days/y2022/day01.rs) | `5.0µs` | `10.0µs` | `20.0µs` | `35.0µs` |\n\
+             | [Day 2](./src/days/y2022/day02.rs) | - | `1.5ms` | - | `1.5ms` |\n\
+             \n**Total: 1.53ms**\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!("# Title\n{}\nold\n{}\nrest\n", MARKER_START, MARKER_END);
+        assert_eq!(
+            replace_section(&readme, "new\n"),
+            Some(format!(
+                "# Title\n{}\nnew\n\n{}\nrest\n",
+                MARKER_START, MARKER_END
+            ))
+        );
+        assert_eq!(replace_section("# Title\n", "new\n"), None);
+    }
+}
@@ -0,0 +1,282 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::{Duration, Instant};
+
+use crate::{bench, bench::BenchResult, cli::RunOptions, output, Answer, DayError, SolveError};
+
+/// Puzzle parameters that differ between the example and the real input, such as the row
+/// day 15 scans. Days without any use `()`.
+pub trait PuzzleParams: 'static {
+    /// Values for the real puzzle input.
+    const REAL: Self;
+    /// Values for the example input.
+    const EXAMPLE: Self;
+}
+
+impl PuzzleParams for () {
+    const REAL: () = ();
+    const EXAMPLE: () = ();
+}
+
+/// Part number that results of the parse phase are reported under.
+pub const PARSE: u8 = 0;
+
+/// "Parse" for the parse phase, "Part N" otherwise.
+pub fn part_label(part: u8) -> String {
+    match part {
+        PARSE => String::from("Parse"),
+        part => format!("Part {}", part),
+    }
+}
+
+/// A day's solution. Every module in `src/days/yYYYY` implements this for its `DayNN` struct
+/// so the runner can call it in-process. The input is parsed once into a `Model`, which is then
+/// passed to both parts. Bad input is reported as a [`SolveError`] instead of a panic, and a part
+/// without a solution yet returns [`SolveError::Unsolved`].
+pub trait Solution {
+    const DAY: u8;
+    type Params: PuzzleParams;
+    /// The parsed input. It may borrow from the input text.
+    type Model<'a>;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, SolveError>;
+    fn part_one(model: &Self::Model<'_>, params: &Self::Params) -> Result<Answer, SolveError>;
+    fn part_two(model: &Self::Model<'_>, params: &Self::Params) -> Result<Answer, SolveError>;
+}
+
+/// Outcome of running one part of a day. `answer` is `None` if the part is not solved yet.
+/// The parse phase is reported as part [`PARSE`] without an answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+}
+
+impl PartResult {
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    pub fn is_parse(&self) -> bool {
+        self.part == PARSE
+    }
+}
+
+// `run_day` of a solution, with its type erased.
+type RunDay = fn(&str, &[u8], &RunOptions) -> Result<Vec<Measurement>, DayError>;
+
+/// Type-erased handle to a [`Solution`], as stored in the registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    run: RunDay,
+    solve: fn(&str, u8) -> Result<PartResult, DayError>,
+}
+
+impl Day {
+    /// Registers a solution for `year`. The runner always solves with the parameters for the
+    /// real input.
+    pub const fn new<S: Solution>(year: u16) -> Self {
+        Day {
+            year,
+            day: S::DAY,
+            run: run_day::<S>,
+            solve: solve_day::<S>,
+        }
+    }
+
+    /// Parses `input` once, then runs the given parts on the parsed model. The parse phase and
+    /// every part are measured on their own, parse first, and benchmarked if `options` ask for
+    /// it. Fails if parsing or a part fails.
+    pub fn run(
+        &self,
+        input: &str,
+        parts: &[u8],
+        options: &RunOptions,
+    ) -> Result<Vec<Measurement>, DayError> {
+        (self.run)(input, parts, options)
+    }
+
+    /// Parses `input` and runs the given part on it once. Only the part is timed.
+    pub fn solve(&self, part: u8, input: &str) -> Result<PartResult, DayError> {
+        (self.solve)(input, part)
+    }
+}
+
+fn solve_model<S: Solution>(model: &S::Model<'_>, part: u8) -> Result<Answer, SolveError> {
+    match part {
+        1 => S::part_one(model, &S::Params::REAL),
+        2 => S::part_two(model, &S::Params::REAL),
+        _ => Err(SolveError::new(format!(
+            "invalid part {}, expected 1 or 2",
+            part
+        ))),
+    }
+}
+
+// Times one parse of `input`, without dropping the model.
+fn time_parse<S: Solution>(input: &str) -> (Result<S::Model<'_>, SolveError>, PartResult) {
+    let timer = Instant::now();
+    let model = S::parse(input);
+    let elapsed = timer.elapsed();
+
+    let result = PartResult {
+        day: S::DAY,
+        part: PARSE,
+        answer: None,
+        elapsed,
+    };
+    (model, result)
+}
+
+fn day_error<S: Solution>(part: u8) -> impl FnOnce(SolveError) -> DayError {
+    move |error| DayError {
+        day: S::DAY,
+        part,
+        error,
+    }
+}
+
+fn run_day<S: Solution>(
+    input: &str,
+    parts: &[u8],
+    options: &RunOptions,
+) -> Result<Vec<Measurement>, DayError> {
+    let (model, parse) = time_parse::<S>(input);
+    let model = model.map_err(day_error::<S>(PARSE))?;
+
+    // Benchmarks parse again on every run. The parts share the model parsed above.
+    let parse = match options.bench {
+        Some(_) => {
+            measure_part(|| Ok(time_parse::<S>(input).1), options).map_err(day_error::<S>(PARSE))?
+        }
+        None => Measurement::Once(parse),
+    };
+
+    let mut measurements = vec![parse];
+    for &part in parts {
+        let measurement = measure_part(
+            || run_part(S::DAY, part, || solve_model::<S>(&model, part)),
+            options,
+        );
+        measurements.push(measurement.map_err(day_error::<S>(part))?);
+    }
+    Ok(measurements)
+}
+
+fn solve_day<S: Solution>(input: &str, part: u8) -> Result<PartResult, DayError> {
+    let model = S::parse(input).map_err(day_error::<S>(PARSE))?;
+    run_part(S::DAY, part, || solve_model::<S>(&model, part)).map_err(day_error::<S>(part))
+}
+
+/// Calls `solver` and measures it. [`SolveError::Unsolved`] is a result without an answer, any
+/// other error is passed on.
+pub fn run_part(
+    day: u8,
+    part: u8,
+    solver: impl FnOnce() -> Result<Answer, SolveError>,
+) -> Result<PartResult, SolveError> {
+    let timer = Instant::now();
+    let answer = solver();
+    let elapsed = timer.elapsed();
+
+    let answer = match answer {
+        Ok(answer) => Some(answer),
+        Err(SolveError::Unsolved) => None,
+        Err(error) => return Err(error),
+    };
+    Ok(PartResult {
+        day,
+        part,
+        answer,
+        elapsed,
+    })
+}
+
+/// A part that has been run once or benchmarked, but not written out yet.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Measurement {
+    Once(PartResult),
+    Bench(BenchResult),
+}
+
+impl Measurement {
+    /// Writes the measurement to stdout in the output format of `options`.
+    pub fn emit(&self, options: &RunOptions) {
+        match self {
+            Measurement::Once(result) => output::emit(result, options),
+            Measurement::Bench(result) => output::emit_bench(result, options),
+        }
+    }
+
+    /// The measurement as a single record. Benchmarks use their median elapsed time.
+    pub fn to_part_result(&self) -> PartResult {
+        match self {
+            Measurement::Once(result) => result.clone(),
+            Measurement::Bench(result) => result.to_part_result(),
+        }
+    }
+}
+
+/// Runs one part once, or benchmarks it if `options` ask for it, without writing it out.
+/// Fails if any run fails, including the warmup runs of a benchmark.
+pub fn measure_part(
+    run: impl Fn() -> Result<PartResult, SolveError>,
+    options: &RunOptions,
+) -> Result<Measurement, SolveError> {
+    Ok(match options.bench {
+        Some(runs) => Measurement::Bench(bench::bench(run, options.warmup, runs)?),
+        None => Measurement::Once(run()?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_part() {
+        let solved = run_part(1, 2, || Ok("abc".len().into())).unwrap();
+        assert_eq!(solved.day, 1);
+        assert_eq!(solved.part, 2);
+        assert_eq!(solved.answer, Some(Answer::from(3)));
+        assert!(solved.is_solved());
+
+        let unsolved = run_part(1, 1, || Err(SolveError::Unsolved)).unwrap();
+        assert_eq!(unsolved.answer, None);
+        assert!(!unsolved.is_solved());
+
+        let failed = run_part(1, 1, || Err(SolveError::new("no path")));
+        assert_eq!(failed, Err(SolveError::new("no path")));
+    }
+
+    #[test]
+    fn test_invalid_part() {
+        let day = crate::days::of_year(2022)[0];
+        let error = day.solve(3, "1000\n").unwrap_err();
+        assert_eq!(error.part, 3);
+        assert_eq!(
+            error.error,
+            SolveError::new("invalid part 3, expected 1 or 2")
+        );
+    }
+
+    #[test]
+    fn test_run_options() {
+        let day = crate::days::of_year(2022)[0];
+        let once = day.run("1000\n", &[1], &RunOptions::default()).unwrap();
+        assert!(matches!(once[1], Measurement::Once(_)));
+
+        let options = RunOptions {
+            bench: Some(3),
+            ..RunOptions::default()
+        };
+        let benched = day.run("1000\n", &[1], &options).unwrap();
+        assert!(matches!(&benched[1], Measurement::Bench(result) if result.runs == 3));
+    }
+}
@@ -0,0 +1,232 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fmt::Display, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{config::default_year, history};
+
+/// How the server judged a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted too soon after the previous attempt. `wait` is the server's remaining time, e.g. "39s".
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part is either solved already or not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong (too high)"),
+            Verdict::TooLow => write!(f, "wrong (too low)"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited ({} left to wait)", wait)
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "already solved or not unlocked yet"),
+        }
+    }
+}
+
+/// One submitted answer.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    /// Attempts recorded before years were tracked belong to [`DEFAULT_YEAR`](crate::config::DEFAULT_YEAR).
+    #[serde(default = "default_year")]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+}
+
+impl Attempt {
+    pub fn new(year: u16, day: u8, part: u8, answer: &str, verdict: Verdict) -> Self {
+        Attempt {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            timestamp: history::now(),
+            verdict,
+        }
+    }
+}
+
+/// Why an answer is not submitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Blocked {
+    /// The part was already answered correctly with `answer`.
+    Solved { answer: String },
+    /// The exact answer was already rejected.
+    Rejected,
+    /// A smaller or equal number was already too high.
+    TooHigh { bound: String },
+    /// A greater or equal number was already too low.
+    TooLow { bound: String },
+}
+
+impl Display for Blocked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Blocked::Solved { answer } => write!(f, "part is already solved with {}", answer),
+            Blocked::Rejected => write!(f, "answer was already rejected"),
+            Blocked::TooHigh { bound } => write!(f, "{} was already too high", bound),
+            Blocked::TooLow { bound } => write!(f, "{} was already too low", bound),
+        }
+    }
+}
+
+/// Every submitted answer, oldest first.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Submissions {
+    pub attempts: Vec<Attempt>,
+}
+
+impl Submissions {
+    /// Loads the submissions at `path`. A missing file has no attempts.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, contents + "\n")
+    }
+
+    pub fn push(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Checks `answer` against earlier attempts. Numeric answers are also checked against
+    /// the closest "too high" and "too low" hints.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), Blocked> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part);
+
+        let mut too_high: Option<i128> = None;
+        let mut too_low: Option<i128> = None;
+
+        for attempt in attempts {
+            if attempt.verdict == Verdict::Correct {
+                return Err(Blocked::Solved {
+                    answer: attempt.answer.clone(),
+                });
+            }
+            if attempt.verdict.is_wrong() && attempt.answer == answer {
+                return Err(Blocked::Rejected);
+            }
+
+            match (&attempt.verdict, attempt.answer.parse::<i128>()) {
+                (Verdict::TooHigh, Ok(value)) => {
+                    too_high = Some(too_high.map_or(value, |bound| bound.min(value)));
+                }
+                (Verdict::TooLow, Ok(value)) => {
+                    too_low = Some(too_low.map_or(value, |bound| bound.max(value)));
+                }
+                _ => {}
+            }
+        }
+
+        if let Ok(answer) = answer.parse::<i128>() {
+            if let Some(bound) = too_high.filter(|&bound| answer >= bound) {
+                return Err(Blocked::TooHigh {
+                    bound: bound.to_string(),
+                });
+            }
+            if let Some(bound) = too_low.filter(|&bound| answer <= bound) {
+                return Err(Blocked::TooLow {
+                    bound: bound.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt::new(2022, 1, part, answer, verdict)
+    }
+
+    #[test]
+    fn test_check() {
+        let mut submissions = Submissions::default();
+        submissions.push(attempt(1, "100", Verdict::TooHigh));
+        submissions.push(attempt(1, "80", Verdict::TooHigh));
+        submissions.push(attempt(1, "20", Verdict::TooLow));
+        submissions.push(attempt(1, "50", Verdict::Wrong));
+        submissions.push(attempt(1, "60", Verdict::RateLimited { wait: None }));
+
+        assert_eq!(submissions.check(2022, 1, 1, "40"), Ok(()));
+        assert_eq!(submissions.check(2022, 1, 1, "60"), Ok(()));
+        assert_eq!(submissions.check(2022, 1, 1, "50"), Err(Blocked::Rejected));
+        assert_eq!(
+            submissions.check(2022, 1, 1, "90"),
+            Err(Blocked::TooHigh { bound: "80".into() })
+        );
+        assert_eq!(
+            submissions.check(2022, 1, 1, "15"),
+            Err(Blocked::TooLow { bound: "20".into() })
+        );
+        assert_eq!(submissions.check(2022, 1, 2, "90"), Ok(()));
+        assert_eq!(submissions.check(2022, 2, 1, "90"), Ok(()));
+        assert_eq!(submissions.check(2021, 1, 1, "90"), Ok(()));
+
+        submissions.push(attempt(1, "42", Verdict::Correct));
+        assert_eq!(
+            submissions.check(2022, 1, 1, "43"),
+            Err(Blocked::Solved {
+                answer: "42".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_text() {
+        let mut submissions = Submissions::default();
+        submissions.push(attempt(1, "ABC", Verdict::Wrong));
+
+        assert_eq!(submissions.check(2022, 1, 1, "ABC"), Err(Blocked::Rejected));
+        assert_eq!(submissions.check(2022, 1, 1, "ABD"), Ok(()));
+
+        let old: Attempt = serde_json::from_str(
+            r#"{"day":1,"part":1,"answer":"ABC","timestamp":0,"verdict":"wrong"}"#,
+        )
+        .unwrap();
+        assert_eq!(old.year, crate::config::DEFAULT_YEAR);
+    }
+}
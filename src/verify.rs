@@ -0,0 +1,158 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{config::config, PartResult};
+
+/// Recorded answers of a year live in `<year>.toml` in the configured `answers` folder.
+pub fn answers_path(year: u16) -> PathBuf {
+    config().answers.join(format!("{}.toml", year))
+}
+
+/// Recorded answers for the real input of a day.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+/// Contents of the answers file, keyed by day module name (`day01`, `day02`, ...).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+impl Answers {
+    /// Loads the answers at `path`. A missing file has no answers.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                toml::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let contents =
+            toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if let Some(folder) = path.as_ref().parent() {
+            fs::create_dir_all(folder)?;
+        }
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        let answers = self.0.get(&key(day))?;
+        match part {
+            1 => answers.part_one.as_deref(),
+            _ => answers.part_two.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, day: u8, part: u8, answer: &str) {
+        let answers = self.0.entry(key(day)).or_default();
+        match part {
+            1 => answers.part_one = Some(answer.to_string()),
+            _ => answers.part_two = Some(answer.to_string()),
+        }
+    }
+}
+
+fn key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+/// Outcome of checking a part against its recorded answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch {
+        expected: String,
+    },
+    /// No answer is recorded for the part.
+    Missing,
+}
+
+pub fn verify(answers: &Answers, result: &PartResult) -> Verdict {
+    match answers.get(result.day, result.part) {
+        None => Verdict::Missing,
+        Some(expected)
+            if result.answer.as_ref().map(|a| a.to_string()).as_deref() == Some(expected) =>
+        {
+            Verdict::Match
+        }
+        Some(expected) => Verdict::Mismatch {
+            expected: expected.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+    use std::time::Duration;
+
+    fn result(day: u8, part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            day,
+            part,
+            answer: answer.map(Answer::from),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers: Answers = toml::from_str(
+            "[day01]\npart_one = \"24000\"\npart_two = \"45000\"\n\n[day05]\npart_one = \"CMZ\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            verify(&answers, &result(1, 1, Some("24000"))),
+            Verdict::Match
+        );
+        assert_eq!(
+            verify(&answers, &result(1, 2, Some("1"))),
+            Verdict::Mismatch {
+                expected: String::from("45000")
+            }
+        );
+        assert_eq!(
+            verify(&answers, &result(1, 2, None)),
+            Verdict::Mismatch {
+                expected: String::from("45000")
+            }
+        );
+        assert_eq!(
+            verify(&answers, &result(5, 2, Some("MCD"))),
+            Verdict::Missing
+        );
+        assert_eq!(
+            verify(&answers, &result(2, 1, Some("15"))),
+            Verdict::Missing
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut answers = Answers::default();
+        answers.set(10, 2, "#..#\n.##.");
+        assert_eq!(answers.get(10, 2), Some("#..#\n.##."));
+        assert_eq!(answers.get(10, 1), None);
+
+        let roundtrip: Answers = toml::from_str(&toml::to_string(&answers).unwrap()).unwrap();
+        assert_eq!(roundtrip, answers);
+    }
+}